@@ -34,7 +34,7 @@ pub extern "C" fn simple_lancedb_connect(
                 }
                 SimpleResult::ok()
             }
-            Err(e) => SimpleResult::error(e.to_string()),
+            Err(e) => SimpleResult::from_lancedb_error("Failed to connect", e),
         }
     });
 
@@ -79,16 +79,9 @@ pub extern "C" fn simple_lancedb_connect_with_options(
         let rt = get_simple_runtime();
 
         match rt.block_on(async {
-            // For now, we'll handle S3 credentials via environment variables or AWS config
-            // This is a simplified approach until LanceDB's API structure is clearer
-
-            // Apply AWS credentials if provided
-            if let Some(s3_config) = storage_options.get("s3_config") {
-                apply_s3_environment_variables(s3_config);
-            }
-
-            // Create connection with URI (storage options applied via environment)
-            connect(&uri_str).execute().await
+            let mut builder = connect(&uri_str);
+            builder = apply_storage_options(builder, &storage_options);
+            builder.execute().await
         }) {
             Ok(conn) => {
                 let boxed_conn = Box::new(conn);
@@ -97,7 +90,7 @@ pub extern "C" fn simple_lancedb_connect_with_options(
                 }
                 SimpleResult::ok()
             }
-            Err(e) => SimpleResult::error(e.to_string()),
+            Err(e) => SimpleResult::from_lancedb_error("Failed to connect", e),
         }
     });
 
@@ -109,36 +102,49 @@ pub extern "C" fn simple_lancedb_connect_with_options(
     }
 }
 
-/// Apply AWS S3 configuration via environment variables
-/// This is a simplified approach that works with most AWS SDK integrations
-fn apply_s3_environment_variables(s3_config: &serde_json::Value) {
-    use std::env;
-
-    // Set AWS credentials via environment variables if provided
-    if let Some(access_key) = s3_config.get("access_key_id").and_then(|v| v.as_str()) {
-        env::set_var("AWS_ACCESS_KEY_ID", access_key);
-    }
-
-    if let Some(secret_key) = s3_config.get("secret_access_key").and_then(|v| v.as_str()) {
-        env::set_var("AWS_SECRET_ACCESS_KEY", secret_key);
-    }
-
-    if let Some(session_token) = s3_config.get("session_token").and_then(|v| v.as_str()) {
-        env::set_var("AWS_SESSION_TOKEN", session_token);
-    }
-
-    if let Some(region) = s3_config.get("region").and_then(|v| v.as_str()) {
-        env::set_var("AWS_REGION", region);
-        env::set_var("AWS_DEFAULT_REGION", region);
-    }
-
-    if let Some(profile) = s3_config.get("profile").and_then(|v| v.as_str()) {
-        env::set_var("AWS_PROFILE", profile);
+/// Storage option keys that every object_store-backed connection understands:
+/// AWS S3 (including S3-compatible endpoints like MinIO/R2), Azure, and GCS.
+const STORAGE_OPTION_KEYS: &[&str] = &[
+    "aws_access_key_id",
+    "aws_secret_access_key",
+    "aws_session_token",
+    "aws_region",
+    "aws_endpoint",
+    "aws_virtual_hosted_style_request",
+    "aws_allow_http",
+    "azure_storage_account_name",
+    "azure_storage_account_key",
+    "azure_storage_sas_key",
+    "google_service_account",
+    "google_service_account_key",
+    "timeout",
+    "connect_timeout",
+];
+
+/// Feed per-connection storage options onto a `ConnectBuilder` instead of
+/// mutating process-global environment variables. Only recognized
+/// object_store-style keys are forwarded; unknown keys are ignored so callers
+/// can pass through a superset of options meant for other connectors.
+fn apply_storage_options(
+    mut builder: lancedb::connection::ConnectBuilder,
+    options: &serde_json::Value,
+) -> lancedb::connection::ConnectBuilder {
+    let Some(options) = options.as_object() else {
+        return builder;
+    };
+
+    for key in STORAGE_OPTION_KEYS {
+        if let Some(value) = options.get(*key).and_then(|v| match v {
+            serde_json::Value::String(s) => Some(s.clone()),
+            serde_json::Value::Bool(b) => Some(b.to_string()),
+            serde_json::Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        }) {
+            builder = builder.storage_option(*key, value);
+        }
     }
 
-    // Note: Other S3 options like custom endpoints, path style, etc. would need
-    // to be supported by LanceDB's connection builder API directly.
-    // For now, this provides basic AWS credential management.
+    builder
 }
 
 /// Close a connection
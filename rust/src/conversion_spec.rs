@@ -0,0 +1,226 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: Copyright The LanceDB Authors
+
+//! Per-column conversion descriptors for JSON ingest and SQL literal updates.
+//!
+//! The plain JSON paths in `conversion.rs` coerce values purely by their
+//! `serde_json` tag, which has no way to express "this string is a
+//! timestamp" or to safely escape a string for use as a SQL literal. This
+//! module parses a small descriptor language (`"int"`, `"timestamp_fmt:..."`,
+//! etc.) that callers attach per-column to say how a raw JSON value should be
+//! converted.
+
+use chrono::{NaiveDateTime, TimeZone, Utc};
+
+/// A conversion to apply to a raw JSON value for a single column.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnConversion {
+    Int,
+    Float,
+    Bool,
+    Bytes,
+    String,
+    /// ISO-8601-style timestamp with no UTC offset (`2024-01-02T03:04:05` or
+    /// with a space), taken as UTC.
+    Timestamp,
+    /// Timestamp parsed with an explicit `strftime`-style format.
+    TimestampFmt(String),
+}
+
+impl ColumnConversion {
+    /// Parse a conversion descriptor, e.g. `"int"` or
+    /// `"timestamp_fmt:%Y-%m-%d %H:%M:%S"`.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        match spec {
+            "int" => Ok(Self::Int),
+            "float" => Ok(Self::Float),
+            "bool" => Ok(Self::Bool),
+            "bytes" => Ok(Self::Bytes),
+            "string" => Ok(Self::String),
+            "timestamp" => Ok(Self::Timestamp),
+            _ if spec.starts_with("timestamp_fmt:") => Ok(Self::TimestampFmt(
+                spec["timestamp_fmt:".len()..].to_string(),
+            )),
+            other => Err(format!("Unknown conversion spec: {}", other)),
+        }
+    }
+}
+
+/// Parse a column -> conversion JSON map, e.g.
+/// `{"created_at": "timestamp_fmt:%Y-%m-%d %H:%M:%S"}`.
+pub fn parse_conversions(
+    conversions_json: &serde_json::Value,
+) -> Result<std::collections::HashMap<String, ColumnConversion>, String> {
+    let Some(obj) = conversions_json.as_object() else {
+        return Err(
+            "Conversions must be a JSON object mapping column name to conversion spec".to_string(),
+        );
+    };
+
+    obj.iter()
+        .map(|(column, spec)| {
+            let spec_str = spec
+                .as_str()
+                .ok_or_else(|| format!("Conversion for column {} must be a string", column))?;
+            Ok((column.clone(), ColumnConversion::parse(spec_str)?))
+        })
+        .collect()
+}
+
+fn scalar_to_string(value: &serde_json::Value, column: &str) -> Result<String, String> {
+    match value {
+        serde_json::Value::String(s) => Ok(s.clone()),
+        serde_json::Value::Number(n) => Ok(n.to_string()),
+        serde_json::Value::Bool(b) => Ok(b.to_string()),
+        other => Err(format!(
+            "Conversion for column {} expects a scalar value, got {}",
+            column, other
+        )),
+    }
+}
+
+/// Parse a raw string into microseconds since the Unix epoch (UTC), per the
+/// given timestamp conversion.
+fn parse_timestamp_micros(
+    conversion: &ColumnConversion,
+    value: &str,
+    column: &str,
+) -> Result<i64, String> {
+    let naive = match conversion {
+        ColumnConversion::Timestamp => NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f")
+            .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f"))
+            .map_err(|e| {
+                format!(
+                    "Invalid timestamp for column {}: {:?} ({})",
+                    column, value, e
+                )
+            })?,
+        ColumnConversion::TimestampFmt(fmt) => {
+            NaiveDateTime::parse_from_str(value, fmt).map_err(|e| {
+                format!(
+                    "Invalid timestamp for column {}: {:?} ({})",
+                    column, value, e
+                )
+            })?
+        }
+        _ => {
+            return Err(format!(
+                "Conversion for column {} is not a timestamp conversion",
+                column
+            ))
+        }
+    };
+    Ok(Utc.from_utc_datetime(&naive).timestamp_micros())
+}
+
+/// Convert a raw JSON value into the canonical JSON shape
+/// `json_to_record_batch` expects for the target Arrow type (a JSON number
+/// for `int`/`float`, a JSON bool for `bool`, a JSON string for
+/// `bytes`/`string`, and microseconds-since-epoch for timestamps, which
+/// `json_to_record_batch_with_conversions` rescales to the column's unit).
+pub fn convert_value(
+    conversion: &ColumnConversion,
+    value: &serde_json::Value,
+    column: &str,
+) -> Result<serde_json::Value, String> {
+    match conversion {
+        ColumnConversion::Int => {
+            let s = scalar_to_string(value, column)?;
+            let i: i64 = s
+                .parse()
+                .map_err(|_| format!("Invalid int for column {}: {:?}", column, s))?;
+            Ok(serde_json::json!(i))
+        }
+        ColumnConversion::Float => {
+            let s = scalar_to_string(value, column)?;
+            let f: f64 = s
+                .parse()
+                .map_err(|_| format!("Invalid float for column {}: {:?}", column, s))?;
+            Ok(serde_json::json!(f))
+        }
+        ColumnConversion::Bool => {
+            let s = scalar_to_string(value, column)?;
+            let b: bool = s
+                .parse()
+                .map_err(|_| format!("Invalid bool for column {}: {:?}", column, s))?;
+            Ok(serde_json::json!(b))
+        }
+        ColumnConversion::Bytes | ColumnConversion::String => {
+            Ok(serde_json::Value::String(scalar_to_string(value, column)?))
+        }
+        ColumnConversion::Timestamp | ColumnConversion::TimestampFmt(_) => {
+            let s = scalar_to_string(value, column)?;
+            let micros = parse_timestamp_micros(conversion, &s, column)?;
+            Ok(serde_json::json!(micros))
+        }
+    }
+}
+
+/// Double up embedded single quotes so a string value can't break out of its
+/// SQL literal. Replaces the naive `format!("'{}'", s)` this module exists to
+/// fix.
+fn escape_sql_string(s: &str) -> String {
+    s.replace('\'', "''")
+}
+
+/// Render a raw update value as a properly typed and escaped SQL literal for
+/// use in a `table.update()` column assignment. `conversion` is `None` for
+/// columns with no explicit conversion spec, in which case values are
+/// rendered by their JSON tag (matching the prior behavior, but with string
+/// escaping fixed).
+pub fn convert_to_sql_literal(
+    conversion: Option<&ColumnConversion>,
+    value: &serde_json::Value,
+    column: &str,
+) -> Result<String, String> {
+    if value.is_null() {
+        return Ok("NULL".to_string());
+    }
+
+    match conversion {
+        None => match value {
+            serde_json::Value::String(s) => Ok(format!("'{}'", escape_sql_string(s))),
+            serde_json::Value::Number(n) => Ok(n.to_string()),
+            serde_json::Value::Bool(b) => Ok(b.to_string()),
+            other => Err(format!(
+                "Unsupported update value type for column {}: {}",
+                column, other
+            )),
+        },
+        Some(ColumnConversion::Int) => {
+            let s = scalar_to_string(value, column)?;
+            let i: i64 = s
+                .parse()
+                .map_err(|_| format!("Invalid int for column {}: {:?}", column, s))?;
+            Ok(i.to_string())
+        }
+        Some(ColumnConversion::Float) => {
+            let s = scalar_to_string(value, column)?;
+            let f: f64 = s
+                .parse()
+                .map_err(|_| format!("Invalid float for column {}: {:?}", column, s))?;
+            Ok(f.to_string())
+        }
+        Some(ColumnConversion::Bool) => {
+            let s = scalar_to_string(value, column)?;
+            let b: bool = s
+                .parse()
+                .map_err(|_| format!("Invalid bool for column {}: {:?}", column, s))?;
+            Ok(b.to_string())
+        }
+        Some(ColumnConversion::Bytes) | Some(ColumnConversion::String) => Ok(format!(
+            "'{}'",
+            escape_sql_string(&scalar_to_string(value, column)?)
+        )),
+        Some(conversion @ ColumnConversion::Timestamp)
+        | Some(conversion @ ColumnConversion::TimestampFmt(_)) => {
+            let s = scalar_to_string(value, column)?;
+            let micros = parse_timestamp_micros(conversion, &s, column)?;
+            let dt = Utc
+                .timestamp_micros(micros)
+                .single()
+                .ok_or_else(|| format!("Invalid timestamp for column {}", column))?;
+            Ok(format!("'{}'", dt.format("%Y-%m-%dT%H:%M:%S%.6f")))
+        }
+    }
+}
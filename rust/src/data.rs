@@ -3,17 +3,123 @@
 
 //! Data CRUD operations
 
-use crate::conversion::json_to_record_batch;
-use crate::ffi::{from_c_str, SimpleResult};
+use crate::conversion::{
+    encode_dictionary_columns, json_to_record_batch, json_to_record_batch_with_conversions,
+    json_to_record_batch_with_dictionary_columns,
+};
+use crate::conversion_spec::{convert_to_sql_literal, parse_conversions};
+use crate::ffi::{from_c_str, SimpleProgressCallback, SimpleResult};
 use crate::runtime::get_simple_runtime;
+use std::ffi::CString;
 use std::os::raw::{c_char, c_void};
 
-/// Delete rows from a table using SQL predicate (simple version)
+/// Number of rows added per `table.add()` call when chunking a bulk insert so
+/// the progress callback has somewhere natural to fire between chunks.
+const ADD_CHUNK_ROWS: usize = 10_000;
+
+/// Split a RecordBatch into fixed-size row chunks for incremental `add()`
+/// calls. Always yields at least one chunk (possibly empty) so a zero-row
+/// batch still reports a single no-op step.
+fn chunk_record_batch(
+    batch: &arrow_array::RecordBatch,
+    chunk_rows: usize,
+) -> Vec<arrow_array::RecordBatch> {
+    let total = batch.num_rows();
+    if total == 0 {
+        return vec![batch.clone()];
+    }
+
+    let mut chunks = Vec::new();
+    let mut offset = 0;
+    while offset < total {
+        let len = chunk_rows.min(total - offset);
+        chunks.push(batch.slice(offset, len));
+        offset += len;
+    }
+    chunks
+}
+
+/// Report progress to the caller's callback, if any; returns `false` if the
+/// caller asked to abort.
+fn report_progress(
+    progress_callback: Option<SimpleProgressCallback>,
+    progress_ctx: *mut c_void,
+    rows_processed: i64,
+    total_rows: i64,
+) -> bool {
+    match progress_callback {
+        Some(callback) => callback(rows_processed, total_rows, progress_ctx),
+        None => true,
+    }
+}
+
+/// Add one or more RecordBatches to a table in fixed-size chunks, reporting
+/// progress between chunks and aborting cleanly (no partial-commit
+/// guarantees) if the callback returns `false`. Shared by the JSON and IPC
+/// ingest entry points. Returns the total number of rows added.
+fn add_chunked(
+    table: &lancedb::Table,
+    rt: &tokio::runtime::Runtime,
+    record_batches: Vec<arrow_array::RecordBatch>,
+    progress_callback: Option<SimpleProgressCallback>,
+    progress_ctx: *mut c_void,
+) -> Result<i64, String> {
+    if record_batches.is_empty() {
+        return Ok(0);
+    }
+
+    let total_rows: i64 = record_batches
+        .iter()
+        .map(|batch| batch.num_rows() as i64)
+        .sum();
+    let schema = record_batches[0].schema();
+    let chunks: Vec<arrow_array::RecordBatch> = record_batches
+        .iter()
+        .flat_map(|batch| chunk_record_batch(batch, ADD_CHUNK_ROWS))
+        .collect();
+
+    let add_result: Result<(), String> = rt.block_on(async {
+        use arrow_array::RecordBatchIterator;
+
+        let mut rows_processed: i64 = 0;
+        for chunk in chunks {
+            let chunk_rows = chunk.num_rows() as i64;
+            let batch_iter = RecordBatchIterator::new(vec![Ok(chunk)], schema.clone());
+            table
+                .add(batch_iter)
+                .execute()
+                .await
+                .map_err(|e| e.to_string())?;
+            rows_processed += chunk_rows;
+
+            if !report_progress(progress_callback, progress_ctx, rows_processed, total_rows) {
+                return Err("cancelled".to_string());
+            }
+        }
+        Ok(())
+    });
+
+    add_result.map(|_| total_rows)
+}
+
+/// Delete rows from a table using SQL predicate (simple version).
+///
+/// `deleted_count` is `-1` unless `report_counts` is set, since LanceDB's
+/// delete result doesn't expose how many rows matched. When `report_counts`
+/// is true, a `count_rows(predicate)` scan runs before the delete inside the
+/// same `block_on`, and the match count (as of execution time, not
+/// necessarily the count actually removed if the predicate itself mutates
+/// concurrently) is written into `deleted_count`. Callers who don't need the
+/// count should leave it unset to avoid paying for the extra scan.
 #[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn simple_lancedb_table_delete(
     table_handle: *mut c_void,
     predicate: *const c_char,
     deleted_count: *mut i64,
+    report_counts: bool,
+    progress_callback: Option<SimpleProgressCallback>,
+    progress_ctx: *mut c_void,
 ) -> *mut SimpleResult {
     let result = std::panic::catch_unwind(|| -> SimpleResult {
         if table_handle.is_null() || predicate.is_null() || deleted_count.is_null() {
@@ -25,19 +131,30 @@ pub extern "C" fn simple_lancedb_table_delete(
             Err(e) => return SimpleResult::error(format!("Invalid predicate: {}", e)),
         };
 
+        if !report_progress(progress_callback, progress_ctx, 0, -1) {
+            return SimpleResult::error("cancelled".to_string());
+        }
+
         let table = unsafe { &*(table_handle as *const lancedb::Table) };
         let rt = get_simple_runtime();
 
-        match rt.block_on(async { table.delete(&predicate_str).await }) {
-            Ok(_delete_result) => {
-                // Note: LanceDB's DeleteResult doesn't expose the number of deleted rows
-                // We set this to -1 to indicate successful deletion but unknown count
+        match rt.block_on(async {
+            let matched = if report_counts {
+                Some(table.count_rows(Some(predicate_str.clone())).await?)
+            } else {
+                None
+            };
+            table.delete(&predicate_str).await?;
+            Ok::<Option<usize>, lancedb::Error>(matched)
+        }) {
+            Ok(matched) => {
                 unsafe {
-                    *deleted_count = -1;
+                    *deleted_count = matched.map(|m| m as i64).unwrap_or(-1);
                 }
+                report_progress(progress_callback, progress_ctx, -1, -1);
                 SimpleResult::ok()
             }
-            Err(e) => SimpleResult::error(format!("Failed to delete rows: {}", e)),
+            Err(e) => SimpleResult::from_lancedb_error("Failed to delete rows", e),
         }
     });
 
@@ -49,15 +166,28 @@ pub extern "C" fn simple_lancedb_table_delete(
     }
 }
 
-/// Update rows in a table using SQL predicate and column updates (simple version)
+/// Update rows in a table using SQL predicate and column updates (simple
+/// version). `updated_count` is left at `-1` unless `report_counts` is set,
+/// for the same reason and with the same `count_rows(predicate)`-before-
+/// update mechanism as `simple_lancedb_table_delete`.
 #[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn simple_lancedb_table_update(
     table_handle: *mut c_void,
     predicate: *const c_char,
     updates_json: *const c_char,
+    conversions_json: *const c_char,
+    report_counts: bool,
+    updated_count: *mut i64,
+    progress_callback: Option<SimpleProgressCallback>,
+    progress_ctx: *mut c_void,
 ) -> *mut SimpleResult {
     let result = std::panic::catch_unwind(|| -> SimpleResult {
-        if table_handle.is_null() || predicate.is_null() || updates_json.is_null() {
+        if table_handle.is_null()
+            || predicate.is_null()
+            || updates_json.is_null()
+            || updated_count.is_null()
+        {
             return SimpleResult::error("Invalid null arguments".to_string());
         }
 
@@ -80,44 +210,70 @@ pub extern "C" fn simple_lancedb_table_update(
                 }
             };
 
-        let table = unsafe { &*(table_handle as *const lancedb::Table) };
-        let rt = get_simple_runtime();
+        // Optional per-column conversion specs (see conversion_spec), e.g. so
+        // a timestamp column can be updated from a formatted string instead
+        // of raw epoch micros.
+        let conversions = if conversions_json.is_null() {
+            None
+        } else {
+            let conversions_str = match from_c_str(conversions_json) {
+                Ok(s) => s,
+                Err(e) => return SimpleResult::error(format!("Invalid conversions JSON: {}", e)),
+            };
+            let conversions_value: serde_json::Value = match serde_json::from_str(&conversions_str)
+            {
+                Ok(v) => v,
+                Err(e) => {
+                    return SimpleResult::error(format!("Failed to parse conversions JSON: {}", e))
+                }
+            };
+            match parse_conversions(&conversions_value) {
+                Ok(c) => Some(c),
+                Err(e) => return SimpleResult::error(e),
+            }
+        };
 
-        // Validate all update values first
+        // Render every update value to a properly typed and escaped SQL
+        // literal up front, so a malformed value is reported before we touch
+        // the table.
+        let mut literals = Vec::with_capacity(updates.len());
         for (column, value) in updates.iter() {
-            match value {
-                serde_json::Value::String(_)
-                | serde_json::Value::Number(_)
-                | serde_json::Value::Bool(_)
-                | serde_json::Value::Null => {}
-                _ => {
-                    return SimpleResult::error(format!(
-                        "Unsupported update value type for column {}",
-                        column
-                    ))
-                }
+            let conversion = conversions.as_ref().and_then(|c| c.get(column));
+            match convert_to_sql_literal(conversion, value, column) {
+                Ok(literal) => literals.push((column.clone(), literal)),
+                Err(e) => return SimpleResult::error(e),
             }
         }
 
+        if !report_progress(progress_callback, progress_ctx, 0, -1) {
+            return SimpleResult::error("cancelled".to_string());
+        }
+
+        let table = unsafe { &*(table_handle as *const lancedb::Table) };
+        let rt = get_simple_runtime();
+
         match rt.block_on(async {
-            let mut update_builder = table.update().only_if(&predicate_str);
+            let matched = if report_counts {
+                Some(table.count_rows(Some(predicate_str.clone())).await?)
+            } else {
+                None
+            };
 
-            // Add each column update separately
-            for (column, value) in updates.iter() {
-                let value_str = match value {
-                    serde_json::Value::String(s) => format!("'{}'", s), // String values need quotes
-                    serde_json::Value::Number(n) => n.to_string(),
-                    serde_json::Value::Bool(b) => b.to_string(),
-                    serde_json::Value::Null => "NULL".to_string(),
-                    _ => unreachable!(), // Already validated above
-                };
-                update_builder = update_builder.column(column, &value_str);
+            let mut update_builder = table.update().only_if(&predicate_str);
+            for (column, literal) in literals.iter() {
+                update_builder = update_builder.column(column, literal);
             }
-
-            update_builder.execute().await
+            update_builder.execute().await?;
+            Ok::<Option<usize>, lancedb::Error>(matched)
         }) {
-            Ok(_update_result) => SimpleResult::ok(),
-            Err(e) => SimpleResult::error(format!("Failed to update rows: {}", e)),
+            Ok(matched) => {
+                unsafe {
+                    *updated_count = matched.map(|m| m as i64).unwrap_or(-1);
+                }
+                report_progress(progress_callback, progress_ctx, -1, -1);
+                SimpleResult::ok()
+            }
+            Err(e) => SimpleResult::from_lancedb_error("Failed to update rows", e),
         }
     });
 
@@ -132,10 +288,13 @@ pub extern "C" fn simple_lancedb_table_update(
 /// Add JSON data to a table (simple version)
 /// Converts JSON array of objects to Arrow RecordBatch and adds to table
 #[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn simple_lancedb_table_add_json(
     table_handle: *mut c_void,
     json_data: *const c_char,
     added_count: *mut i64,
+    progress_callback: Option<SimpleProgressCallback>,
+    progress_ctx: *mut c_void,
 ) -> *mut SimpleResult {
     let result = std::panic::catch_unwind(|| -> SimpleResult {
         if table_handle.is_null() || json_data.is_null() || added_count.is_null() {
@@ -173,19 +332,15 @@ pub extern "C" fn simple_lancedb_table_add_json(
         // Convert JSON to RecordBatch
         match json_to_record_batch(&json_values, &table_schema) {
             Ok(record_batch) => {
-                // Add the record batch to the table
-                match rt.block_on(async {
-                    use arrow_array::RecordBatchIterator;
-                    let batches = vec![Ok(record_batch.clone())];
-                    let batch_iter = RecordBatchIterator::new(batches, record_batch.schema());
-                    table.add(batch_iter).execute().await
-                }) {
-                    Ok(_) => {
+                match add_chunked(table, &rt, vec![record_batch], progress_callback, progress_ctx)
+                {
+                    Ok(total_rows) => {
                         unsafe {
-                            *added_count = record_batch.num_rows() as i64;
+                            *added_count = total_rows;
                         }
                         SimpleResult::ok()
                     }
+                    Err(e) if e == "cancelled" => SimpleResult::error("cancelled".to_string()),
                     Err(e) => SimpleResult::error(format!("Failed to add data to table: {}", e)),
                 }
             }
@@ -201,14 +356,220 @@ pub extern "C" fn simple_lancedb_table_add_json(
     }
 }
 
+/// Add JSON data to a table, honoring a per-column conversion map (see
+/// `conversion_spec`) so values like formatted timestamps can be ingested
+/// into columns whose Arrow type has no native JSON representation.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn simple_lancedb_table_add_json_with_conversions(
+    table_handle: *mut c_void,
+    json_data: *const c_char,
+    conversions_json: *const c_char,
+    added_count: *mut i64,
+    progress_callback: Option<SimpleProgressCallback>,
+    progress_ctx: *mut c_void,
+) -> *mut SimpleResult {
+    let result = std::panic::catch_unwind(|| -> SimpleResult {
+        if table_handle.is_null()
+            || json_data.is_null()
+            || conversions_json.is_null()
+            || added_count.is_null()
+        {
+            return SimpleResult::error("Invalid null arguments".to_string());
+        }
+
+        let json_str = match from_c_str(json_data) {
+            Ok(s) => s,
+            Err(e) => return SimpleResult::error(format!("Invalid JSON data: {}", e)),
+        };
+
+        let conversions_str = match from_c_str(conversions_json) {
+            Ok(s) => s,
+            Err(e) => return SimpleResult::error(format!("Invalid conversions JSON: {}", e)),
+        };
+        let conversions_value: serde_json::Value = match serde_json::from_str(&conversions_str) {
+            Ok(v) => v,
+            Err(e) => {
+                return SimpleResult::error(format!("Failed to parse conversions JSON: {}", e))
+            }
+        };
+        let conversions = match parse_conversions(&conversions_value) {
+            Ok(c) => c,
+            Err(e) => return SimpleResult::error(e),
+        };
+
+        let table = unsafe { &*(table_handle as *const lancedb::Table) };
+        let rt = get_simple_runtime();
+
+        // Parse JSON array
+        let json_values: Vec<serde_json::Value> = match serde_json::from_str(&json_str) {
+            Ok(serde_json::Value::Array(arr)) => arr,
+            Ok(single_value) => vec![single_value], // Convert single object to array
+            Err(e) => return SimpleResult::error(format!("Failed to parse JSON: {}", e)),
+        };
+
+        if json_values.is_empty() {
+            unsafe {
+                *added_count = 0;
+            }
+            return SimpleResult::ok();
+        }
+
+        // Get table schema
+        let table_schema = match rt.block_on(async { table.schema().await }) {
+            Ok(schema) => schema,
+            Err(e) => return SimpleResult::error(format!("Failed to get table schema: {}", e)),
+        };
+
+        // Convert JSON to RecordBatch, applying the column conversions
+        match json_to_record_batch_with_conversions(&json_values, &table_schema, Some(&conversions))
+        {
+            Ok(record_batch) => {
+                match add_chunked(table, &rt, vec![record_batch], progress_callback, progress_ctx)
+                {
+                    Ok(total_rows) => {
+                        unsafe {
+                            *added_count = total_rows;
+                        }
+                        SimpleResult::ok()
+                    }
+                    Err(e) if e == "cancelled" => SimpleResult::error("cancelled".to_string()),
+                    Err(e) => SimpleResult::error(format!("Failed to add data to table: {}", e)),
+                }
+            }
+            Err(e) => SimpleResult::error(format!("Failed to convert JSON to RecordBatch: {}", e)),
+        }
+    });
+
+    match result {
+        Ok(res) => Box::into_raw(Box::new(res)),
+        Err(_) => Box::into_raw(Box::new(SimpleResult::error(
+            "Panic in simple_lancedb_table_add_json_with_conversions".to_string(),
+        ))),
+    }
+}
+
+/// Parse a JSON array of strings, e.g. a list of column names.
+fn parse_string_list(json_str: &str) -> Result<Vec<String>, String> {
+    match serde_json::from_str::<serde_json::Value>(json_str) {
+        Ok(serde_json::Value::Array(arr)) => arr
+            .into_iter()
+            .map(|v| {
+                v.as_str()
+                    .map(|s| s.to_string())
+                    .ok_or_else(|| "Expected an array of strings".to_string())
+            })
+            .collect(),
+        Ok(_) => Err("Expected a JSON array of strings".to_string()),
+        Err(e) => Err(format!("Failed to parse JSON array: {}", e)),
+    }
+}
+
+/// Add JSON data to a table, dictionary-encoding the named string columns
+/// (`Dictionary(Int32, Utf8)` instead of a plain string column) to shrink
+/// storage and speed scans on low-cardinality categorical fields like
+/// `status` or `region`.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn simple_lancedb_table_add_json_with_dictionary_columns(
+    table_handle: *mut c_void,
+    json_data: *const c_char,
+    dictionary_columns_json: *const c_char,
+    added_count: *mut i64,
+    progress_callback: Option<SimpleProgressCallback>,
+    progress_ctx: *mut c_void,
+) -> *mut SimpleResult {
+    let result = std::panic::catch_unwind(|| -> SimpleResult {
+        if table_handle.is_null()
+            || json_data.is_null()
+            || dictionary_columns_json.is_null()
+            || added_count.is_null()
+        {
+            return SimpleResult::error("Invalid null arguments".to_string());
+        }
+
+        let json_str = match from_c_str(json_data) {
+            Ok(s) => s,
+            Err(e) => return SimpleResult::error(format!("Invalid JSON data: {}", e)),
+        };
+
+        let dictionary_columns_str = match from_c_str(dictionary_columns_json) {
+            Ok(s) => s,
+            Err(e) => {
+                return SimpleResult::error(format!("Invalid dictionary columns JSON: {}", e))
+            }
+        };
+        let dictionary_columns = match parse_string_list(&dictionary_columns_str) {
+            Ok(columns) => columns,
+            Err(e) => return SimpleResult::error(format!("Invalid dictionary columns: {}", e)),
+        };
+
+        let table = unsafe { &*(table_handle as *const lancedb::Table) };
+        let rt = get_simple_runtime();
+
+        // Parse JSON array
+        let json_values: Vec<serde_json::Value> = match serde_json::from_str(&json_str) {
+            Ok(serde_json::Value::Array(arr)) => arr,
+            Ok(single_value) => vec![single_value], // Convert single object to array
+            Err(e) => return SimpleResult::error(format!("Failed to parse JSON: {}", e)),
+        };
+
+        if json_values.is_empty() {
+            unsafe {
+                *added_count = 0;
+            }
+            return SimpleResult::ok();
+        }
+
+        // Get table schema
+        let table_schema = match rt.block_on(async { table.schema().await }) {
+            Ok(schema) => schema,
+            Err(e) => return SimpleResult::error(format!("Failed to get table schema: {}", e)),
+        };
+
+        // Convert JSON to RecordBatch, dictionary-encoding the requested columns
+        match json_to_record_batch_with_dictionary_columns(
+            &json_values,
+            &table_schema,
+            None,
+            &dictionary_columns,
+        ) {
+            Ok(record_batch) => {
+                match add_chunked(table, &rt, vec![record_batch], progress_callback, progress_ctx)
+                {
+                    Ok(total_rows) => {
+                        unsafe {
+                            *added_count = total_rows;
+                        }
+                        SimpleResult::ok()
+                    }
+                    Err(e) if e == "cancelled" => SimpleResult::error("cancelled".to_string()),
+                    Err(e) => SimpleResult::error(format!("Failed to add data to table: {}", e)),
+                }
+            }
+            Err(e) => SimpleResult::error(format!("Failed to convert JSON to RecordBatch: {}", e)),
+        }
+    });
+
+    match result {
+        Ok(res) => Box::into_raw(Box::new(res)),
+        Err(_) => Box::into_raw(Box::new(SimpleResult::error(
+            "Panic in simple_lancedb_table_add_json_with_dictionary_columns".to_string(),
+        ))),
+    }
+}
+
 /// Add data to a table using Arrow IPC format (more efficient than JSON)
 /// Accepts batch of records as Arrow IPC binary data
 #[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn simple_lancedb_table_add_ipc(
     table_handle: *mut c_void,
     ipc_data: *const u8,
     ipc_len: usize,
     added_count: *mut i64,
+    progress_callback: Option<SimpleProgressCallback>,
+    progress_ctx: *mut c_void,
 ) -> *mut SimpleResult {
     let result = std::panic::catch_unwind(|| -> SimpleResult {
         if table_handle.is_null() || ipc_data.is_null() || added_count.is_null() {
@@ -238,29 +599,271 @@ pub extern "C" fn simple_lancedb_table_add_ipc(
                     return SimpleResult::ok();
                 }
 
-                // Calculate total rows across all batches
-                let total_rows: usize = record_batches.iter().map(|batch| batch.num_rows()).sum();
+                match add_chunked(table, &rt, record_batches, progress_callback, progress_ctx) {
+                    Ok(total_rows) => {
+                        unsafe {
+                            *added_count = total_rows;
+                        }
+                        SimpleResult::ok()
+                    }
+                    Err(e) if e == "cancelled" => SimpleResult::error("cancelled".to_string()),
+                    Err(e) => SimpleResult::error(format!("Failed to add data to table: {}", e)),
+                }
+            }
+            Err(e) => SimpleResult::error(format!("Failed to parse IPC data: {}", e)),
+        }
+    });
+
+    match result {
+        Ok(res) => Box::into_raw(Box::new(res)),
+        Err(_) => Box::into_raw(Box::new(SimpleResult::error(
+            "Panic in simple_lancedb_table_add_ipc".to_string(),
+        ))),
+    }
+}
+
+/// Execute a single batch operation (`add`/`delete`/`update`) against an
+/// already-open table, returning the number of affected rows where known
+/// (`-1` for delete/update, which mirrors `simple_lancedb_table_delete`'s
+/// "succeeded but count unknown" convention).
+async fn execute_batch_op(table: &lancedb::Table, op: &serde_json::Value) -> Result<i64, String> {
+    let op_name = op
+        .get("op")
+        .and_then(|v| v.as_str())
+        .ok_or("Batch operation missing 'op' field")?;
+
+    match op_name {
+        "add" => {
+            let data = op.get("data").ok_or("'add' operation missing 'data' field")?;
+            let json_values: Vec<serde_json::Value> = match data {
+                serde_json::Value::Array(arr) => arr.clone(),
+                other => vec![other.clone()],
+            };
+            if json_values.is_empty() {
+                return Ok(0);
+            }
+
+            let table_schema = table
+                .schema()
+                .await
+                .map_err(|e| format!("Failed to get table schema: {}", e))?;
+            let record_batch = json_to_record_batch(&json_values, &table_schema)?;
+            let row_count = record_batch.num_rows() as i64;
+
+            use arrow_array::RecordBatchIterator;
+            let schema = record_batch.schema();
+            let batch_iter = RecordBatchIterator::new(vec![Ok(record_batch)], schema);
+            table
+                .add(batch_iter)
+                .execute()
+                .await
+                .map_err(|e| format!("Failed to add data to table: {}", e))?;
+            Ok(row_count)
+        }
+        "delete" => {
+            let predicate = op
+                .get("predicate")
+                .and_then(|v| v.as_str())
+                .ok_or("'delete' operation missing 'predicate' field")?;
+            table
+                .delete(predicate)
+                .await
+                .map_err(|e| format!("Failed to delete rows: {}", e))?;
+            Ok(-1)
+        }
+        "update" => {
+            let predicate = op
+                .get("predicate")
+                .and_then(|v| v.as_str())
+                .ok_or("'update' operation missing 'predicate' field")?;
+            let updates = op
+                .get("updates")
+                .and_then(|v| v.as_object())
+                .ok_or("'update' operation missing 'updates' object")?;
+            let conversions = match op.get("conversions") {
+                Some(c) => Some(parse_conversions(c)?),
+                None => None,
+            };
+
+            let mut update_builder = table.update().only_if(predicate);
+            for (column, value) in updates.iter() {
+                let conversion = conversions.as_ref().and_then(|c| c.get(column));
+                let literal = convert_to_sql_literal(conversion, value, column)?;
+                update_builder = update_builder.column(column, &literal);
+            }
+            update_builder
+                .execute()
+                .await
+                .map_err(|e| format!("Failed to update rows: {}", e))?;
+            Ok(-1)
+        }
+        other => Err(format!("Unknown batch operation: {}", other)),
+    }
+}
+
+/// Execute an ordered JSON array of add/delete/update operations against one
+/// runtime `block_on`, so a Go caller can flush a mixed changeset (e.g.
+/// upsert-like delete-then-add plus targeted updates) without paying one FFI
+/// round-trip per logical change. Operations run in order; the first
+/// failing operation stops the batch. `result_json` always reports the
+/// outcome of every attempted op plus the first failed index (`null` if all
+/// succeeded), so the caller can tell exactly how far the batch got.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn simple_lancedb_table_batch(
+    table_handle: *mut c_void,
+    ops_json: *const c_char,
+    result_json: *mut *mut c_char,
+) -> *mut SimpleResult {
+    let result = std::panic::catch_unwind(|| -> SimpleResult {
+        if table_handle.is_null() || ops_json.is_null() || result_json.is_null() {
+            return SimpleResult::error("Invalid null arguments".to_string());
+        }
+
+        let ops_str = match from_c_str(ops_json) {
+            Ok(s) => s,
+            Err(e) => return SimpleResult::error(format!("Invalid batch JSON: {}", e)),
+        };
+
+        let ops: Vec<serde_json::Value> = match serde_json::from_str(&ops_str) {
+            Ok(serde_json::Value::Array(arr)) => arr,
+            Ok(_) => {
+                return SimpleResult::error("Batch JSON must be an array of operations".to_string())
+            }
+            Err(e) => return SimpleResult::error(format!("Failed to parse batch JSON: {}", e)),
+        };
+
+        let table = unsafe { &*(table_handle as *const lancedb::Table) };
+        let rt = get_simple_runtime();
+
+        let mut op_results = Vec::with_capacity(ops.len());
+        let failed_index: Option<usize> = rt.block_on(async {
+            for (index, op) in ops.iter().enumerate() {
+                match execute_batch_op(table, op).await {
+                    Ok(count) => op_results.push(serde_json::json!({
+                        "success": true,
+                        "count": count,
+                    })),
+                    Err(e) => {
+                        op_results.push(serde_json::json!({
+                            "success": false,
+                            "error": e,
+                        }));
+                        return Some(index);
+                    }
+                }
+            }
+            None
+        });
+
+        let response = serde_json::json!({
+            "results": op_results,
+            "failed_index": failed_index,
+        });
 
-                // Add the record batches to the table
-                match rt.block_on(async {
-                    use arrow_array::RecordBatchIterator;
+        match serde_json::to_string(&response) {
+            Ok(json_str) => {
+                let c_str = CString::new(json_str).unwrap();
+                unsafe {
+                    *result_json = c_str.into_raw();
+                }
+                match failed_index {
+                    Some(index) => {
+                        SimpleResult::error(format!("Batch operation {} failed", index))
+                    }
+                    None => SimpleResult::ok(),
+                }
+            }
+            Err(e) => SimpleResult::error(format!("Failed to serialize batch result: {}", e)),
+        }
+    });
 
-                    // Get schema from the first batch
-                    let schema = record_batches[0].schema();
+    match result {
+        Ok(res) => Box::into_raw(Box::new(res)),
+        Err(_) => Box::into_raw(Box::new(SimpleResult::error(
+            "Panic in simple_lancedb_table_batch".to_string(),
+        ))),
+    }
+}
 
-                    // Create iterator from record batches
-                    let batches: Vec<Result<arrow_array::RecordBatch, arrow_schema::ArrowError>> =
-                        record_batches.into_iter().map(Ok).collect();
-                    let batch_iter = RecordBatchIterator::new(batches, schema);
+/// Add data to a table using Arrow IPC format, dictionary-encoding the named
+/// string columns the same way as
+/// `simple_lancedb_table_add_json_with_dictionary_columns`.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn simple_lancedb_table_add_ipc_with_dictionary_columns(
+    table_handle: *mut c_void,
+    ipc_data: *const u8,
+    ipc_len: usize,
+    dictionary_columns_json: *const c_char,
+    added_count: *mut i64,
+    progress_callback: Option<SimpleProgressCallback>,
+    progress_ctx: *mut c_void,
+) -> *mut SimpleResult {
+    let result = std::panic::catch_unwind(|| -> SimpleResult {
+        if table_handle.is_null()
+            || ipc_data.is_null()
+            || dictionary_columns_json.is_null()
+            || added_count.is_null()
+        {
+            return SimpleResult::error("Invalid null arguments".to_string());
+        }
+
+        let dictionary_columns_str = match from_c_str(dictionary_columns_json) {
+            Ok(s) => s,
+            Err(e) => {
+                return SimpleResult::error(format!("Invalid dictionary columns JSON: {}", e))
+            }
+        };
+        let dictionary_columns = match parse_string_list(&dictionary_columns_str) {
+            Ok(columns) => columns,
+            Err(e) => return SimpleResult::error(format!("Invalid dictionary columns: {}", e)),
+        };
+
+        if ipc_len == 0 {
+            unsafe {
+                *added_count = 0;
+            }
+            return SimpleResult::ok();
+        }
+
+        let table = unsafe { &*(table_handle as *const lancedb::Table) };
+        let rt = get_simple_runtime();
 
-                    table.add(batch_iter).execute().await
-                }) {
-                    Ok(_) => {
+        let ipc_bytes = unsafe { std::slice::from_raw_parts(ipc_data, ipc_len) };
+
+        match ipc_to_record_batches(ipc_bytes) {
+            Ok(record_batches) => {
+                if record_batches.is_empty() {
+                    unsafe {
+                        *added_count = 0;
+                    }
+                    return SimpleResult::ok();
+                }
+
+                let encoded: Result<Vec<arrow_array::RecordBatch>, String> = record_batches
+                    .into_iter()
+                    .map(|batch| encode_dictionary_columns(batch, &dictionary_columns))
+                    .collect();
+
+                let record_batches = match encoded {
+                    Ok(batches) => batches,
+                    Err(e) => {
+                        return SimpleResult::error(format!(
+                            "Failed to dictionary-encode IPC data: {}",
+                            e
+                        ))
+                    }
+                };
+
+                match add_chunked(table, &rt, record_batches, progress_callback, progress_ctx) {
+                    Ok(total_rows) => {
                         unsafe {
-                            *added_count = total_rows as i64;
+                            *added_count = total_rows;
                         }
                         SimpleResult::ok()
                     }
+                    Err(e) if e == "cancelled" => SimpleResult::error("cancelled".to_string()),
                     Err(e) => SimpleResult::error(format!("Failed to add data to table: {}", e)),
                 }
             }
@@ -271,7 +874,7 @@ pub extern "C" fn simple_lancedb_table_add_ipc(
     match result {
         Ok(res) => Box::into_raw(Box::new(res)),
         Err(_) => Box::into_raw(Box::new(SimpleResult::error(
-            "Panic in simple_lancedb_table_add_ipc".to_string(),
+            "Panic in simple_lancedb_table_add_ipc_with_dictionary_columns".to_string(),
         ))),
     }
 }
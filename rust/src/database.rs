@@ -53,7 +53,7 @@ pub extern "C" fn simple_lancedb_table_names(
                 }
                 SimpleResult::ok()
             }
-            Err(e) => SimpleResult::error(e.to_string()),
+            Err(e) => SimpleResult::from_lancedb_error("Failed to list table names", e),
         }
     });
 
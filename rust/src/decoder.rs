@@ -0,0 +1,452 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: Copyright The LanceDB Authors
+
+//! Streaming, tape-based JSON decoding into Arrow `RecordBatch`es.
+//!
+//! The previous implementation of [`crate::conversion::json_to_record_batch`]
+//! needed a fully materialized `Vec<serde_json::Value>` up front and
+//! re-scanned it once per schema field. [`RecordBatchDecoder`] instead
+//! tokenizes each top-level JSON object directly off the wire into a flat
+//! "tape" of [`TapeEvent`]s - index ranges into the raw bytes rather than an
+//! owned tree - then does one pass over the tape per row to fan each key out
+//! to its schema field via a precomputed name-to-index map. Input can be fed
+//! incrementally through [`RecordBatchDecoder::decode`], so newline-delimited
+//! JSON or chunked network buffers never need the whole payload in memory as
+//! `Value`s. `json_to_record_batch` itself now delegates to
+//! [`json_to_record_batch_via_decoder`] for its non-streaming case.
+
+use crate::conversion::{build_array_for_field, unwrap_extension_value_for_field};
+use arrow_array::ArrayRef;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// The kind of scalar a [`TapeEvent::Scalar`] spans, cheap to check without
+/// re-parsing the underlying bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScalarKind {
+    Null,
+    Bool,
+    Number,
+    String,
+}
+
+/// One token produced while scanning a JSON value. Ranges index into the
+/// original input bytes rather than owning a copy, so tokenizing is a single
+/// pass with no intermediate tree allocation.
+#[derive(Debug, Clone)]
+enum TapeEvent {
+    StartObject(usize),
+    StartArray(usize),
+    /// An object member's key, including its surrounding quotes.
+    Key(Range<usize>),
+    /// A scalar value's raw JSON text (e.g. `"foo"`, `123`, `true`, `null`).
+    Scalar(Range<usize>, ScalarKind),
+    /// Position just past the closing `}`/`]` of the most recently opened
+    /// object/array.
+    End(usize),
+}
+
+/// Incrementally decodes newline- or whitespace-delimited JSON objects into
+/// an Arrow `RecordBatch`, one row per top-level object.
+///
+/// Unknown keys are skipped without aborting the row; schema fields absent
+/// from a row are left `null` (subject to the field's own nullability, the
+/// same as [`crate::conversion::json_to_record_batch`]).
+pub struct RecordBatchDecoder {
+    schema: arrow_schema::Schema,
+    field_index: HashMap<String, usize>,
+    columns: Vec<Vec<Option<serde_json::Value>>>,
+    buffer: Vec<u8>,
+}
+
+impl RecordBatchDecoder {
+    pub fn new(schema: arrow_schema::Schema) -> Self {
+        let field_index = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| (field.name().clone(), i))
+            .collect();
+        let columns = vec![Vec::new(); schema.fields().len()];
+
+        Self {
+            schema,
+            field_index,
+            columns,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Feed a chunk of input bytes. Every complete top-level JSON object
+    /// buffered so far is tokenized and folded into the accumulated columns;
+    /// a trailing partial value is kept and retried once more bytes arrive.
+    pub fn decode(&mut self, bytes: &[u8]) -> Result<(), String> {
+        self.buffer.extend_from_slice(bytes);
+
+        loop {
+            let pos = skip_ws(&self.buffer, 0);
+            if pos >= self.buffer.len() {
+                self.buffer.clear();
+                return Ok(());
+            }
+
+            let mut events = Vec::new();
+            match scan_value(&self.buffer, pos, &mut events)? {
+                None => return Ok(()), // Incomplete; wait for more bytes.
+                Some(end) => {
+                    self.ingest_row(&events)?;
+                    self.buffer.drain(0..end);
+                }
+            }
+        }
+    }
+
+    /// Finish decoding and assemble the accumulated rows into a
+    /// `RecordBatch`. Errors if bytes remain that don't form a complete
+    /// value, i.e. the input was truncated mid-object.
+    pub fn flush(mut self) -> Result<arrow_array::RecordBatch, String> {
+        if skip_ws(&self.buffer, 0) < self.buffer.len() {
+            return Err("Unexpected trailing bytes: incomplete JSON value".to_string());
+        }
+
+        let mut arrays: Vec<ArrayRef> = Vec::with_capacity(self.schema.fields().len());
+        for (field, values) in self.schema.fields().iter().zip(self.columns.drain(..)) {
+            arrays.push(build_array_for_field(&values, field)?);
+        }
+
+        arrow_array::RecordBatch::try_new(Arc::new(self.schema), arrays)
+            .map_err(|e| format!("Failed to create RecordBatch: {}", e))
+    }
+
+    /// Fold one top-level object's tape into `self.columns`, resolving each
+    /// key against `field_index` and leaving unresolved schema fields `None`
+    /// for this row.
+    fn ingest_row(&mut self, events: &[TapeEvent]) -> Result<(), String> {
+        if !matches!(events.first(), Some(TapeEvent::StartObject(_))) {
+            return Err("Top-level JSON value must be an object".to_string());
+        }
+
+        let mut row: Vec<Option<serde_json::Value>> = vec![None; self.columns.len()];
+        let mut i = 1;
+        while i < events.len() {
+            match &events[i] {
+                TapeEvent::Key(key_range) => {
+                    let raw_key = std::str::from_utf8(&self.buffer[key_range.clone()])
+                        .map_err(|e| format!("Invalid UTF-8 in JSON key: {}", e))?;
+                    let key = &raw_key[1..raw_key.len() - 1]; // strip quotes
+
+                    let (value_span, next_i, scalar_kind) = value_span_at(events, i + 1)?;
+                    if let Some(&field_idx) = self.field_index.get(key) {
+                        let value = if scalar_kind == Some(ScalarKind::Null) {
+                            serde_json::Value::Null
+                        } else {
+                            serde_json::from_slice(&self.buffer[value_span]).map_err(|e| {
+                                format!("Invalid JSON value for field {}: {}", key, e)
+                            })?
+                        };
+                        let field = &self.schema.fields()[field_idx];
+                        row[field_idx] =
+                            Some(unwrap_extension_value_for_field(&value, field).clone());
+                    }
+                    i = next_i;
+                }
+                TapeEvent::End(_) => break,
+                _ => return Err("Malformed object tape".to_string()),
+            }
+        }
+
+        for (column, value) in self.columns.iter_mut().zip(row) {
+            column.push(value);
+        }
+        Ok(())
+    }
+}
+
+/// Returns the byte range covered by the value starting at `events[i]`, the
+/// tape index just past it, and its `ScalarKind` if it is a scalar.
+fn value_span_at(
+    events: &[TapeEvent],
+    i: usize,
+) -> Result<(Range<usize>, usize, Option<ScalarKind>), String> {
+    match events.get(i) {
+        Some(TapeEvent::Scalar(range, kind)) => Ok((range.clone(), i + 1, Some(*kind))),
+        Some(TapeEvent::StartObject(start)) | Some(TapeEvent::StartArray(start)) => {
+            let start = *start;
+            let mut depth = 1;
+            let mut j = i + 1;
+            while j < events.len() && depth > 0 {
+                match &events[j] {
+                    TapeEvent::StartObject(_) | TapeEvent::StartArray(_) => depth += 1,
+                    TapeEvent::End(_) => depth -= 1,
+                    _ => {}
+                }
+                j += 1;
+            }
+            match events.get(j - 1) {
+                Some(TapeEvent::End(end)) => Ok((start..*end, j, None)),
+                _ => Err("Unbalanced JSON tape".to_string()),
+            }
+        }
+        _ => Err("Expected a value on the tape".to_string()),
+    }
+}
+
+fn skip_ws(bytes: &[u8], mut pos: usize) -> usize {
+    while pos < bytes.len() && matches!(bytes[pos], b' ' | b'\t' | b'\n' | b'\r') {
+        pos += 1;
+    }
+    pos
+}
+
+/// Scans exactly one JSON value starting at `pos` (leading whitespace is
+/// skipped), pushing its tokens onto `events`. Returns the position just
+/// past the value, or `None` if the buffer ends before the value is
+/// complete - the caller should retain the bytes and retry once more data
+/// arrives.
+fn scan_value(
+    bytes: &[u8],
+    pos: usize,
+    events: &mut Vec<TapeEvent>,
+) -> Result<Option<usize>, String> {
+    let pos = skip_ws(bytes, pos);
+    if pos >= bytes.len() {
+        return Ok(None);
+    }
+
+    match bytes[pos] {
+        b'{' => scan_object(bytes, pos, events),
+        b'[' => scan_array(bytes, pos, events),
+        b'"' => {
+            let checkpoint = events.len();
+            match scan_string(bytes, pos)? {
+                Some(end) => {
+                    events.push(TapeEvent::Scalar(pos..end, ScalarKind::String));
+                    Ok(Some(end))
+                }
+                None => {
+                    events.truncate(checkpoint);
+                    Ok(None)
+                }
+            }
+        }
+        b't' | b'f' | b'n' => scan_literal(bytes, pos, events),
+        b'0'..=b'9' | b'-' => scan_number(bytes, pos, events),
+        other => Err(format!(
+            "Unexpected byte {:?} in JSON input at offset {}",
+            other as char, pos
+        )),
+    }
+}
+
+fn scan_object(
+    bytes: &[u8],
+    pos: usize,
+    events: &mut Vec<TapeEvent>,
+) -> Result<Option<usize>, String> {
+    let checkpoint = events.len();
+    events.push(TapeEvent::StartObject(pos));
+
+    let mut i = skip_ws(bytes, pos + 1);
+    if i < bytes.len() && bytes[i] == b'}' {
+        events.push(TapeEvent::End(i + 1));
+        return Ok(Some(i + 1));
+    }
+
+    loop {
+        i = skip_ws(bytes, i);
+        if i >= bytes.len() {
+            events.truncate(checkpoint);
+            return Ok(None);
+        }
+        if bytes[i] != b'"' {
+            return Err(format!("Expected object key at offset {}", i));
+        }
+        let key_start = i;
+        let key_end = match scan_string(bytes, i)? {
+            Some(end) => end,
+            None => {
+                events.truncate(checkpoint);
+                return Ok(None);
+            }
+        };
+        events.push(TapeEvent::Key(key_start..key_end));
+
+        i = skip_ws(bytes, key_end);
+        if i >= bytes.len() {
+            events.truncate(checkpoint);
+            return Ok(None);
+        }
+        if bytes[i] != b':' {
+            return Err(format!("Expected ':' after object key at offset {}", i));
+        }
+        i += 1;
+
+        i = match scan_value(bytes, i, events)? {
+            Some(end) => end,
+            None => {
+                events.truncate(checkpoint);
+                return Ok(None);
+            }
+        };
+
+        i = skip_ws(bytes, i);
+        if i >= bytes.len() {
+            events.truncate(checkpoint);
+            return Ok(None);
+        }
+        match bytes[i] {
+            b',' => {
+                i += 1;
+            }
+            b'}' => {
+                events.push(TapeEvent::End(i + 1));
+                return Ok(Some(i + 1));
+            }
+            _ => return Err(format!("Expected ',' or '}}' in object at offset {}", i)),
+        }
+    }
+}
+
+fn scan_array(
+    bytes: &[u8],
+    pos: usize,
+    events: &mut Vec<TapeEvent>,
+) -> Result<Option<usize>, String> {
+    let checkpoint = events.len();
+    events.push(TapeEvent::StartArray(pos));
+
+    let mut i = skip_ws(bytes, pos + 1);
+    if i < bytes.len() && bytes[i] == b']' {
+        events.push(TapeEvent::End(i + 1));
+        return Ok(Some(i + 1));
+    }
+
+    loop {
+        i = match scan_value(bytes, i, events)? {
+            Some(end) => end,
+            None => {
+                events.truncate(checkpoint);
+                return Ok(None);
+            }
+        };
+
+        i = skip_ws(bytes, i);
+        if i >= bytes.len() {
+            events.truncate(checkpoint);
+            return Ok(None);
+        }
+        match bytes[i] {
+            b',' => {
+                i += 1;
+            }
+            b']' => {
+                events.push(TapeEvent::End(i + 1));
+                return Ok(Some(i + 1));
+            }
+            _ => return Err(format!("Expected ',' or ']' in array at offset {}", i)),
+        }
+    }
+}
+
+/// Scans a string literal starting at the opening quote, honoring backslash
+/// escapes so an embedded `\"` doesn't end the scan early. Returns `None`
+/// (rather than an error) if the buffer ends before the closing quote, since
+/// that's indistinguishable from "more bytes are coming".
+fn scan_string(bytes: &[u8], pos: usize) -> Result<Option<usize>, String> {
+    let mut i = pos + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return Ok(Some(i + 1)),
+            _ => i += 1,
+        }
+    }
+    Ok(None)
+}
+
+fn scan_number(
+    bytes: &[u8],
+    pos: usize,
+    events: &mut Vec<TapeEvent>,
+) -> Result<Option<usize>, String> {
+    let mut i = pos;
+    if i < bytes.len() && bytes[i] == b'-' {
+        i += 1;
+    }
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        i += 1;
+    }
+    if i < bytes.len() && bytes[i] == b'.' {
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+    }
+    if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+        i += 1;
+        if i < bytes.len() && (bytes[i] == b'+' || bytes[i] == b'-') {
+            i += 1;
+        }
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+    }
+
+    if i >= bytes.len() {
+        // The number could continue in the next chunk - wait for more data.
+        return Ok(None);
+    }
+    events.push(TapeEvent::Scalar(pos..i, ScalarKind::Number));
+    Ok(Some(i))
+}
+
+fn scan_literal(
+    bytes: &[u8],
+    pos: usize,
+    events: &mut Vec<TapeEvent>,
+) -> Result<Option<usize>, String> {
+    const LITERALS: &[(&[u8], ScalarKind)] = &[
+        (b"true", ScalarKind::Bool),
+        (b"false", ScalarKind::Bool),
+        (b"null", ScalarKind::Null),
+    ];
+
+    let rest = &bytes[pos..];
+    for (literal, kind) in LITERALS {
+        if rest.len() < literal.len() {
+            // Not enough bytes yet to tell, but what we do have matches the
+            // literal's prefix - wait for more data instead of erroring.
+            if rest == &literal[..rest.len()] {
+                return Ok(None);
+            }
+            continue;
+        }
+        if &rest[..literal.len()] == *literal {
+            events.push(TapeEvent::Scalar(pos..pos + literal.len(), *kind));
+            return Ok(Some(pos + literal.len()));
+        }
+    }
+    Err(format!("Invalid literal at offset {}", pos))
+}
+
+/// Convenience entry point for callers that already hold a full
+/// `Vec<serde_json::Value>` in memory: re-serializes each value and feeds it
+/// through a [`RecordBatchDecoder`], then flushes immediately. This is what
+/// [`crate::conversion::json_to_record_batch`] delegates to for the
+/// non-streaming case; use
+/// [`crate::conversion::json_to_record_batch_with_conversions`] instead when
+/// per-column [`crate::conversion_spec::ColumnConversion`]s are needed, since
+/// the tape decoder doesn't apply those.
+pub fn json_to_record_batch_via_decoder(
+    json_values: &[serde_json::Value],
+    schema: &arrow_schema::Schema,
+) -> Result<arrow_array::RecordBatch, String> {
+    let mut decoder = RecordBatchDecoder::new(schema.clone());
+    for value in json_values {
+        let bytes =
+            serde_json::to_vec(value).map_err(|e| format!("Failed to serialize row: {}", e))?;
+        decoder.decode(&bytes)?;
+    }
+    decoder.flush()
+}
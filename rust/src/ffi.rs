@@ -4,14 +4,44 @@
 //! Core FFI infrastructure and result types
 
 use std::ffi::{CStr, CString};
-use std::os::raw::{c_char, c_int};
+use std::os::raw::{c_char, c_int, c_void};
 use std::ptr;
 
+/// Progress/cancellation callback for long-running ingestion and DML
+/// operations. Called periodically with rows processed so far and the total
+/// row count, either of which is `-1` when not known. Returning `false`
+/// aborts the operation.
+pub type SimpleProgressCallback =
+    extern "C" fn(rows_processed: i64, total_rows: i64, ctx: *mut c_void) -> bool;
+
+/// Callback invoked once per Arrow IPC message (the schema message, one per
+/// `RecordBatch`, then the end-of-stream marker) when streaming table rows in
+/// chunked mode. Returning `false` aborts the scan.
+pub type SimpleIpcChunkCallback = extern "C" fn(data: *const u8, len: usize, ctx: *mut c_void) -> bool;
+
+/// Error category classifying why an operation failed, so Go callers can
+/// branch on the failure kind (e.g. retry on `Io`, surface `NotFound`
+/// specially) instead of string-matching `error_message`. Exposed in the
+/// generated header as plain `int` values.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SimpleErrorCode {
+    Ok = 0,
+    NotFound = 1,
+    InvalidArgument = 2,
+    SchemaMismatch = 3,
+    Io = 4,
+    AlreadyExists = 5,
+    Unsupported = 6,
+    Internal = 7,
+}
+
 /// Result type for C interface
 #[repr(C)]
 pub struct SimpleResult {
     pub success: bool,
     pub error_message: *mut c_char,
+    pub error_code: c_int,
 }
 
 impl SimpleResult {
@@ -19,16 +49,53 @@ impl SimpleResult {
         Self {
             success: true,
             error_message: ptr::null_mut(),
+            error_code: SimpleErrorCode::Ok as c_int,
         }
     }
 
+    /// Build a failure result with the `InvalidArgument` code. Used for
+    /// input-validation failures (bad JSON, null arguments, unparseable
+    /// predicates, ...) and for other failures that have no `lancedb::Error`
+    /// to classify.
     pub fn error(msg: String) -> Self {
+        Self::error_with_code(msg, SimpleErrorCode::InvalidArgument)
+    }
+
+    pub fn error_with_code(msg: String, code: SimpleErrorCode) -> Self {
         let c_msg =
             CString::new(msg).unwrap_or_else(|_| CString::new("Invalid error message").unwrap());
         Self {
             success: false,
             error_message: c_msg.into_raw(),
+            error_code: code as c_int,
+        }
+    }
+
+    /// Build a failure result from a `lancedb::Error`, classifying it into a
+    /// `SimpleErrorCode` so Go callers can distinguish e.g. a missing table
+    /// from a transient object-store timeout without parsing `message`.
+    /// `context` is prefixed onto the message the same way the existing
+    /// `format!("Failed to ...: {}", e)` call sites already read.
+    pub fn from_lancedb_error(context: &str, err: lancedb::Error) -> Self {
+        let code = classify_lancedb_error(&err);
+        Self::error_with_code(format!("{}: {}", context, err), code)
+    }
+}
+
+/// Classify a `lancedb::Error` into the coarse category Go callers need to
+/// branch on. Unrecognized/library-internal variants fall back to
+/// `Internal` rather than guessing.
+fn classify_lancedb_error(err: &lancedb::Error) -> SimpleErrorCode {
+    match err {
+        lancedb::Error::TableNotFound { .. } => SimpleErrorCode::NotFound,
+        lancedb::Error::TableAlreadyExists { .. } => SimpleErrorCode::AlreadyExists,
+        lancedb::Error::InvalidTableName { .. } | lancedb::Error::InvalidInput { .. } => {
+            SimpleErrorCode::InvalidArgument
         }
+        lancedb::Error::Schema { .. } => SimpleErrorCode::SchemaMismatch,
+        lancedb::Error::NotSupported { .. } => SimpleErrorCode::Unsupported,
+        lancedb::Error::CreateDir { .. } | lancedb::Error::Store { .. } => SimpleErrorCode::Io,
+        _ => SimpleErrorCode::Internal,
     }
 }
 
@@ -8,6 +8,249 @@ use crate::runtime::get_simple_runtime;
 use std::ffi::CString;
 use std::os::raw::{c_char, c_void};
 
+/// Tunable parameters for the vector index builders, parsed from
+/// `config_json`. Fields left unset keep the builder's `::default()` value.
+/// Unknown JSON keys are ignored; a present-but-invalid value is a parse
+/// error rather than falling back to the default.
+struct VectorIndexConfig {
+    distance_type: Option<lancedb::DistanceType>,
+    num_partitions: Option<u32>,
+    sample_rate: Option<u32>,
+    max_iterations: Option<u32>,
+    num_sub_vectors: Option<u32>,
+    num_bits: Option<u32>,
+    m: Option<u32>,
+    ef_construction: Option<u32>,
+}
+
+fn parse_distance_type(s: &str) -> Result<lancedb::DistanceType, String> {
+    match s {
+        "l2" => Ok(lancedb::DistanceType::L2),
+        "cosine" => Ok(lancedb::DistanceType::Cosine),
+        "dot" => Ok(lancedb::DistanceType::Dot),
+        other => Err(format!("Unsupported distance type: {}", other)),
+    }
+}
+
+fn config_u32(obj: &serde_json::Map<String, serde_json::Value>, key: &str) -> Result<Option<u32>, String> {
+    match obj.get(key) {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(v) => v
+            .as_u64()
+            .and_then(|n| u32::try_from(n).ok())
+            .map(Some)
+            .ok_or_else(|| format!("Invalid value for {}: {}", key, v)),
+    }
+}
+
+fn parse_vector_index_config(config_str: &str) -> Result<VectorIndexConfig, String> {
+    let value: serde_json::Value = serde_json::from_str(config_str)
+        .map_err(|e| format!("Failed to parse index config JSON: {}", e))?;
+    let obj = value
+        .as_object()
+        .ok_or("Index config must be a JSON object")?;
+
+    let distance_type = match obj.get("distance_type") {
+        None | Some(serde_json::Value::Null) => None,
+        Some(serde_json::Value::String(s)) => Some(parse_distance_type(s)?),
+        Some(other) => return Err(format!("Invalid distance_type: {}", other)),
+    };
+
+    Ok(VectorIndexConfig {
+        distance_type,
+        num_partitions: config_u32(obj, "num_partitions")?,
+        sample_rate: config_u32(obj, "sample_rate")?,
+        max_iterations: config_u32(obj, "max_iterations")?,
+        num_sub_vectors: config_u32(obj, "num_sub_vectors")?,
+        num_bits: config_u32(obj, "num_bits")?,
+        m: config_u32(obj, "m")?,
+        ef_construction: config_u32(obj, "ef_construction")?,
+    })
+}
+
+fn apply_ivf_pq_config(
+    mut builder: lancedb::index::vector::IvfPqIndexBuilder,
+    config: &VectorIndexConfig,
+) -> lancedb::index::vector::IvfPqIndexBuilder {
+    if let Some(v) = config.distance_type {
+        builder = builder.distance_type(v);
+    }
+    if let Some(v) = config.num_partitions {
+        builder = builder.num_partitions(v);
+    }
+    if let Some(v) = config.sample_rate {
+        builder = builder.sample_rate(v);
+    }
+    if let Some(v) = config.max_iterations {
+        builder = builder.max_iterations(v);
+    }
+    if let Some(v) = config.num_sub_vectors {
+        builder = builder.num_sub_vectors(v);
+    }
+    if let Some(v) = config.num_bits {
+        builder = builder.num_bits(v);
+    }
+    builder
+}
+
+fn apply_ivf_flat_config(
+    mut builder: lancedb::index::vector::IvfFlatIndexBuilder,
+    config: &VectorIndexConfig,
+) -> lancedb::index::vector::IvfFlatIndexBuilder {
+    if let Some(v) = config.distance_type {
+        builder = builder.distance_type(v);
+    }
+    if let Some(v) = config.num_partitions {
+        builder = builder.num_partitions(v);
+    }
+    if let Some(v) = config.sample_rate {
+        builder = builder.sample_rate(v);
+    }
+    if let Some(v) = config.max_iterations {
+        builder = builder.max_iterations(v);
+    }
+    builder
+}
+
+fn apply_ivf_hnsw_pq_config(
+    mut builder: lancedb::index::vector::IvfHnswPqIndexBuilder,
+    config: &VectorIndexConfig,
+) -> lancedb::index::vector::IvfHnswPqIndexBuilder {
+    if let Some(v) = config.distance_type {
+        builder = builder.distance_type(v);
+    }
+    if let Some(v) = config.num_partitions {
+        builder = builder.num_partitions(v);
+    }
+    if let Some(v) = config.sample_rate {
+        builder = builder.sample_rate(v);
+    }
+    if let Some(v) = config.max_iterations {
+        builder = builder.max_iterations(v);
+    }
+    if let Some(v) = config.num_sub_vectors {
+        builder = builder.num_sub_vectors(v);
+    }
+    if let Some(v) = config.num_bits {
+        builder = builder.num_bits(v);
+    }
+    if let Some(v) = config.m {
+        builder = builder.m(v);
+    }
+    if let Some(v) = config.ef_construction {
+        builder = builder.ef_construction(v);
+    }
+    builder
+}
+
+fn apply_ivf_hnsw_sq_config(
+    mut builder: lancedb::index::vector::IvfHnswSqIndexBuilder,
+    config: &VectorIndexConfig,
+) -> lancedb::index::vector::IvfHnswSqIndexBuilder {
+    if let Some(v) = config.distance_type {
+        builder = builder.distance_type(v);
+    }
+    if let Some(v) = config.num_partitions {
+        builder = builder.num_partitions(v);
+    }
+    if let Some(v) = config.sample_rate {
+        builder = builder.sample_rate(v);
+    }
+    if let Some(v) = config.max_iterations {
+        builder = builder.max_iterations(v);
+    }
+    if let Some(v) = config.m {
+        builder = builder.m(v);
+    }
+    if let Some(v) = config.ef_construction {
+        builder = builder.ef_construction(v);
+    }
+    builder
+}
+
+/// Tunable parameters for `FtsIndexBuilder`, parsed from `config_json`.
+/// Fields left unset keep the builder's `::default()` value (English,
+/// position-less).
+struct FtsIndexConfig {
+    with_position: Option<bool>,
+    base_tokenizer: Option<String>,
+    language: Option<String>,
+    lower_case: Option<bool>,
+    stem: Option<bool>,
+    remove_stop_words: Option<bool>,
+    ascii_folding: Option<bool>,
+}
+
+fn config_bool(
+    obj: &serde_json::Map<String, serde_json::Value>,
+    key: &str,
+) -> Result<Option<bool>, String> {
+    match obj.get(key) {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::Bool(b)) => Ok(Some(*b)),
+        Some(other) => Err(format!("Invalid value for {}: {}", key, other)),
+    }
+}
+
+fn config_string(
+    obj: &serde_json::Map<String, serde_json::Value>,
+    key: &str,
+) -> Result<Option<String>, String> {
+    match obj.get(key) {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::String(s)) => Ok(Some(s.clone())),
+        Some(other) => Err(format!("Invalid value for {}: {}", key, other)),
+    }
+}
+
+fn parse_fts_index_config(config_str: &str) -> Result<FtsIndexConfig, String> {
+    let value: serde_json::Value = serde_json::from_str(config_str)
+        .map_err(|e| format!("Failed to parse index config JSON: {}", e))?;
+    let obj = value
+        .as_object()
+        .ok_or("Index config must be a JSON object")?;
+
+    Ok(FtsIndexConfig {
+        with_position: config_bool(obj, "with_position")?,
+        base_tokenizer: config_string(obj, "base_tokenizer")?,
+        language: config_string(obj, "language")?,
+        lower_case: config_bool(obj, "lower_case")?,
+        stem: config_bool(obj, "stem")?,
+        remove_stop_words: config_bool(obj, "remove_stop_words")?,
+        ascii_folding: config_bool(obj, "ascii_folding")?,
+    })
+}
+
+fn apply_fts_config(
+    mut builder: lancedb::index::scalar::FtsIndexBuilder,
+    config: &FtsIndexConfig,
+) -> Result<lancedb::index::scalar::FtsIndexBuilder, String> {
+    if let Some(v) = config.with_position {
+        builder = builder.with_position(v);
+    }
+    if let Some(v) = &config.base_tokenizer {
+        builder = builder.base_tokenizer(v.clone());
+    }
+    if let Some(v) = &config.language {
+        builder = builder
+            .language(v)
+            .map_err(|e| format!("Invalid FTS language: {}", e))?;
+    }
+    if let Some(v) = config.lower_case {
+        builder = builder.lower_case(v);
+    }
+    if let Some(v) = config.stem {
+        builder = builder.stem(v);
+    }
+    if let Some(v) = config.remove_stop_words {
+        builder = builder.remove_stop_words(v);
+    }
+    if let Some(v) = config.ascii_folding {
+        builder = builder.ascii_folding(v);
+    }
+    Ok(builder)
+}
+
 /// Create an index on the specified columns
 #[no_mangle]
 pub extern "C" fn simple_lancedb_table_create_index(
@@ -15,6 +258,7 @@ pub extern "C" fn simple_lancedb_table_create_index(
     columns_json: *const c_char,
     index_type: *const c_char,
     index_name: *const c_char,
+    config_json: *const c_char,
 ) -> *mut SimpleResult {
     let result = std::panic::catch_unwind(|| -> SimpleResult {
         if table_handle.is_null() || columns_json.is_null() || index_type.is_null() {
@@ -40,6 +284,31 @@ pub extern "C" fn simple_lancedb_table_create_index(
             }
         };
 
+        let config_str = if config_json.is_null() {
+            None
+        } else {
+            match from_c_str(config_json) {
+                Ok(s) => Some(s),
+                Err(e) => return SimpleResult::error(format!("Invalid index config: {}", e)),
+            }
+        };
+
+        let index_config = match &config_str {
+            Some(s) => match parse_vector_index_config(s) {
+                Ok(c) => Some(c),
+                Err(e) => return SimpleResult::error(e),
+            },
+            None => None,
+        };
+
+        let fts_config = match &config_str {
+            Some(s) => match parse_fts_index_config(s) {
+                Ok(c) => Some(c),
+                Err(e) => return SimpleResult::error(e),
+            },
+            None => None,
+        };
+
         // Parse columns JSON
         let columns: Vec<String> = match serde_json::from_str(&columns_str) {
             Ok(cols) => cols,
@@ -54,12 +323,13 @@ pub extern "C" fn simple_lancedb_table_create_index(
             "vector" | "ivf_pq" => {
                 // Create vector index (IVF_PQ)
                 rt.block_on(async {
-                    let mut index_builder = table.create_index(
-                        &columns,
-                        lancedb::index::Index::IvfPq(
-                            lancedb::index::vector::IvfPqIndexBuilder::default(),
-                        ),
-                    );
+                    let mut builder = lancedb::index::vector::IvfPqIndexBuilder::default();
+                    if let Some(config) = &index_config {
+                        builder = apply_ivf_pq_config(builder, config);
+                    }
+
+                    let mut index_builder =
+                        table.create_index(&columns, lancedb::index::Index::IvfPq(builder));
 
                     if let Some(name) = index_name_str {
                         index_builder = index_builder.name(name);
@@ -69,12 +339,13 @@ pub extern "C" fn simple_lancedb_table_create_index(
                 })
             }
             "ivf_flat" => rt.block_on(async {
-                let mut index_builder = table.create_index(
-                    &columns,
-                    lancedb::index::Index::IvfFlat(
-                        lancedb::index::vector::IvfFlatIndexBuilder::default(),
-                    ),
-                );
+                let mut builder = lancedb::index::vector::IvfFlatIndexBuilder::default();
+                if let Some(config) = &index_config {
+                    builder = apply_ivf_flat_config(builder, config);
+                }
+
+                let mut index_builder =
+                    table.create_index(&columns, lancedb::index::Index::IvfFlat(builder));
 
                 if let Some(name) = index_name_str {
                     index_builder = index_builder.name(name);
@@ -83,12 +354,13 @@ pub extern "C" fn simple_lancedb_table_create_index(
                 index_builder.execute().await
             }),
             "hnsw_pq" => rt.block_on(async {
-                let mut index_builder = table.create_index(
-                    &columns,
-                    lancedb::index::Index::IvfHnswPq(
-                        lancedb::index::vector::IvfHnswPqIndexBuilder::default(),
-                    ),
-                );
+                let mut builder = lancedb::index::vector::IvfHnswPqIndexBuilder::default();
+                if let Some(config) = &index_config {
+                    builder = apply_ivf_hnsw_pq_config(builder, config);
+                }
+
+                let mut index_builder =
+                    table.create_index(&columns, lancedb::index::Index::IvfHnswPq(builder));
 
                 if let Some(name) = index_name_str {
                     index_builder = index_builder.name(name);
@@ -97,12 +369,13 @@ pub extern "C" fn simple_lancedb_table_create_index(
                 index_builder.execute().await
             }),
             "hnsw_sq" => rt.block_on(async {
-                let mut index_builder = table.create_index(
-                    &columns,
-                    lancedb::index::Index::IvfHnswSq(
-                        lancedb::index::vector::IvfHnswSqIndexBuilder::default(),
-                    ),
-                );
+                let mut builder = lancedb::index::vector::IvfHnswSqIndexBuilder::default();
+                if let Some(config) = &index_config {
+                    builder = apply_ivf_hnsw_sq_config(builder, config);
+                }
+
+                let mut index_builder =
+                    table.create_index(&columns, lancedb::index::Index::IvfHnswSq(builder));
 
                 if let Some(name) = index_name_str {
                     index_builder = index_builder.name(name);
@@ -148,24 +421,32 @@ pub extern "C" fn simple_lancedb_table_create_index(
 
                 index_builder.execute().await
             }),
-            "fts" => rt.block_on(async {
-                let mut index_builder = table.create_index(
-                    &columns,
-                    lancedb::index::Index::FTS(lancedb::index::scalar::FtsIndexBuilder::default()),
-                );
-
-                if let Some(name) = index_name_str {
-                    index_builder = index_builder.name(name);
+            "fts" => {
+                let mut builder = lancedb::index::scalar::FtsIndexBuilder::default();
+                if let Some(config) = &fts_config {
+                    builder = match apply_fts_config(builder, config) {
+                        Ok(b) => b,
+                        Err(e) => return SimpleResult::error(e),
+                    };
                 }
 
-                index_builder.execute().await
-            }),
+                rt.block_on(async {
+                    let mut index_builder =
+                        table.create_index(&columns, lancedb::index::Index::FTS(builder));
+
+                    if let Some(name) = index_name_str {
+                        index_builder = index_builder.name(name);
+                    }
+
+                    index_builder.execute().await
+                })
+            }
             _ => return SimpleResult::error(format!("Unsupported index type: {}", index_type_str)),
         };
 
         match index_result {
             Ok(_) => SimpleResult::ok(),
-            Err(e) => SimpleResult::error(format!("Failed to create index: {}", e)),
+            Err(e) => SimpleResult::from_lancedb_error("Failed to create index", e),
         }
     });
 
@@ -223,7 +504,7 @@ pub extern "C" fn simple_lancedb_table_get_indexes(
                     }
                 }
             }
-            Err(e) => SimpleResult::error(format!("Failed to list indexes: {}", e)),
+            Err(e) => SimpleResult::from_lancedb_error("Failed to list indexes", e),
         }
     });
 
@@ -284,7 +565,7 @@ pub extern "C" fn simple_lancedb_table_index_stats(
                 }
             }
             Ok(None) => SimpleResult::ok(),
-            Err(e) => SimpleResult::error(format!("Failed to get index stats: {}", e)),
+            Err(e) => SimpleResult::from_lancedb_error("Failed to get index stats", e),
         }
     });
 
@@ -5,8 +5,10 @@
 
 pub mod connection;
 pub mod conversion;
+pub mod conversion_spec;
 pub mod data;
 pub mod database;
+pub mod decoder;
 pub mod ffi;
 pub mod index;
 pub mod metadata;
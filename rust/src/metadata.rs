@@ -3,8 +3,9 @@
 
 //! Table metadata operations
 
-use crate::ffi::{SimpleResult};
+use crate::ffi::SimpleResult;
 use crate::runtime::get_simple_runtime;
+use crate::schema::arrow_schema_to_json;
 use std::ffi::CString;
 use std::os::raw::{c_char, c_void};
 
@@ -29,7 +30,7 @@ pub extern "C" fn simple_lancedb_table_count_rows(
                 }
                 SimpleResult::ok()
             }
-            Err(e) => SimpleResult::error(format!("Failed to count rows: {}", e)),
+            Err(e) => SimpleResult::from_lancedb_error("Failed to count rows", e),
         }
     });
 
@@ -62,7 +63,7 @@ pub extern "C" fn simple_lancedb_table_version(
                 }
                 SimpleResult::ok()
             }
-            Err(e) => SimpleResult::error(format!("Failed to get table version: {}", e)),
+            Err(e) => SimpleResult::from_lancedb_error("Failed to get table version", e),
         }
     });
 
@@ -74,60 +75,216 @@ pub extern "C" fn simple_lancedb_table_version(
     }
 }
 
-/// Get table schema as JSON (simple version)
+/// Pin a table handle's reads to a historical version
 #[no_mangle]
-pub extern "C" fn simple_lancedb_table_schema(
+pub extern "C" fn simple_lancedb_table_checkout_version(
     table_handle: *mut c_void,
-    schema_json: *mut *mut c_char,
+    version: i64,
 ) -> *mut SimpleResult {
     let result = std::panic::catch_unwind(|| -> SimpleResult {
-        if table_handle.is_null() || schema_json.is_null() {
+        if table_handle.is_null() {
             return SimpleResult::error("Invalid null arguments".to_string());
         }
+        if version < 0 {
+            return SimpleResult::error("Version must be non-negative".to_string());
+        }
 
         let table = unsafe { &*(table_handle as *const lancedb::Table) };
         let rt = get_simple_runtime();
 
-        match rt.block_on(async { table.schema().await }) {
-            Ok(arrow_schema) => {
-                // Convert Arrow schema to JSON
-                let fields: Vec<serde_json::Value> = arrow_schema
-                    .fields()
-                    .iter()
-                    .map(|field| {
-                        let type_str = match field.data_type() {
-                            arrow_schema::DataType::Int32 => "int32",
-                            arrow_schema::DataType::Int64 => "int64",
-                            arrow_schema::DataType::Float32 => "float32",
-                            arrow_schema::DataType::Float64 => "float64",
-                            arrow_schema::DataType::Utf8 => "string",
-                            arrow_schema::DataType::Binary => "binary",
-                            arrow_schema::DataType::Boolean => "boolean",
-                            arrow_schema::DataType::FixedSizeList(inner, size) => {
-                                if matches!(inner.data_type(), arrow_schema::DataType::Float32) {
-                                    return serde_json::json!({
-                                        "name": field.name(),
-                                        "type": format!("fixed_size_list[float32;{}]", size),
-                                        "nullable": field.is_nullable()
-                                    });
-                                } else {
-                                    "unknown"
-                                }
-                            }
-                            _ => "unknown",
-                        };
+        match rt.block_on(async { table.checkout(version as u64).await }) {
+            Ok(_) => SimpleResult::ok(),
+            Err(e) => SimpleResult::from_lancedb_error("Failed to checkout version", e),
+        }
+    });
 
+    match result {
+        Ok(res) => Box::into_raw(Box::new(res)),
+        Err(_) => Box::into_raw(Box::new(SimpleResult::error(
+            "Panic in simple_lancedb_table_checkout_version".to_string(),
+        ))),
+    }
+}
+
+/// List the table's snapshot history (one entry per committed version), for
+/// reproducible reads and rollback against a fixed version, mirroring
+/// snapshot-based table formats.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn simple_lancedb_table_list_versions(
+    table_handle: *mut c_void,
+    versions_json: *mut *mut c_char,
+) -> *mut SimpleResult {
+    let result = std::panic::catch_unwind(|| -> SimpleResult {
+        if table_handle.is_null() || versions_json.is_null() {
+            return SimpleResult::error("Invalid null arguments".to_string());
+        }
+
+        let table = unsafe { &*(table_handle as *const lancedb::Table) };
+        let rt = get_simple_runtime();
+
+        match rt.block_on(async { table.list_versions().await }) {
+            Ok(versions) => {
+                let versions_info: Vec<serde_json::Value> = versions
+                    .into_iter()
+                    .map(|v| {
                         serde_json::json!({
-                            "name": field.name(),
-                            "type": type_str,
-                            "nullable": field.is_nullable()
+                            "version": v.version,
+                            "timestamp": v.timestamp.to_rfc3339(),
+                            "metadata": v.metadata,
                         })
                     })
                     .collect();
 
-                let schema_json_obj = serde_json::json!({
-                    "fields": fields
-                });
+                match serde_json::to_string(&versions_info) {
+                    Ok(json_str) => match CString::new(json_str) {
+                        Ok(c_string) => {
+                            unsafe {
+                                *versions_json = c_string.into_raw();
+                            }
+                            SimpleResult::ok()
+                        }
+                        Err(_) => {
+                            SimpleResult::error("Failed to convert JSON to C string".to_string())
+                        }
+                    },
+                    Err(e) => {
+                        SimpleResult::error(format!("Failed to serialize versions to JSON: {}", e))
+                    }
+                }
+            }
+            Err(e) => SimpleResult::from_lancedb_error("Failed to list versions", e),
+        }
+    });
+
+    match result {
+        Ok(res) => Box::into_raw(Box::new(res)),
+        Err(_) => Box::into_raw(Box::new(SimpleResult::error(
+            "Panic in simple_lancedb_table_list_versions".to_string(),
+        ))),
+    }
+}
+
+/// Release a checked-out historical version and pin the table handle back to
+/// the latest version.
+#[no_mangle]
+pub extern "C" fn simple_lancedb_table_checkout_latest(
+    table_handle: *mut c_void,
+) -> *mut SimpleResult {
+    let result = std::panic::catch_unwind(|| -> SimpleResult {
+        if table_handle.is_null() {
+            return SimpleResult::error("Invalid null arguments".to_string());
+        }
+
+        let table = unsafe { &*(table_handle as *const lancedb::Table) };
+        let rt = get_simple_runtime();
+
+        match rt.block_on(async { table.checkout_latest().await }) {
+            Ok(_) => SimpleResult::ok(),
+            Err(e) => SimpleResult::from_lancedb_error("Failed to checkout latest version", e),
+        }
+    });
+
+    match result {
+        Ok(res) => Box::into_raw(Box::new(res)),
+        Err(_) => Box::into_raw(Box::new(SimpleResult::error(
+            "Panic in simple_lancedb_table_checkout_latest".to_string(),
+        ))),
+    }
+}
+
+/// Promote the table handle's currently checked-out version to a new latest
+/// version, rolling the table back to that snapshot.
+#[no_mangle]
+pub extern "C" fn simple_lancedb_table_restore(table_handle: *mut c_void) -> *mut SimpleResult {
+    let result = std::panic::catch_unwind(|| -> SimpleResult {
+        if table_handle.is_null() {
+            return SimpleResult::error("Invalid null arguments".to_string());
+        }
+
+        let table = unsafe { &*(table_handle as *const lancedb::Table) };
+        let rt = get_simple_runtime();
+
+        match rt.block_on(async { table.restore().await }) {
+            Ok(_) => SimpleResult::ok(),
+            Err(e) => SimpleResult::from_lancedb_error("Failed to restore version", e),
+        }
+    });
+
+    match result {
+        Ok(res) => Box::into_raw(Box::new(res)),
+        Err(_) => Box::into_raw(Box::new(SimpleResult::error(
+            "Panic in simple_lancedb_table_restore".to_string(),
+        ))),
+    }
+}
+
+/// Long-poll for a table version newer than `known_version`, waiting up to
+/// `timeout_ms` before returning the current version. Lets Go callers build
+/// change-driven reindexing without busy-looping.
+#[no_mangle]
+pub extern "C" fn simple_lancedb_table_poll_version(
+    table_handle: *mut c_void,
+    known_version: i64,
+    timeout_ms: i64,
+    out_version: *mut i64,
+) -> *mut SimpleResult {
+    let result = std::panic::catch_unwind(|| -> SimpleResult {
+        if table_handle.is_null() || out_version.is_null() {
+            return SimpleResult::error("Invalid null arguments".to_string());
+        }
+
+        let table = unsafe { &*(table_handle as *const lancedb::Table) };
+        let rt = get_simple_runtime();
+        let deadline = std::time::Duration::from_millis(timeout_ms.max(0) as u64);
+
+        const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(50);
+
+        match rt.block_on(async {
+            let start = tokio::time::Instant::now();
+            loop {
+                let current = table.version().await?;
+                if current > known_version as u64 || start.elapsed() >= deadline {
+                    return Ok(current);
+                }
+                tokio::time::sleep(POLL_INTERVAL).await;
+            }
+        }) {
+            Ok(current_version) => {
+                unsafe {
+                    *out_version = current_version as i64;
+                }
+                SimpleResult::ok()
+            }
+            Err(e) => SimpleResult::from_lancedb_error("Failed to poll table version", e),
+        }
+    });
+
+    match result {
+        Ok(res) => Box::into_raw(Box::new(res)),
+        Err(_) => Box::into_raw(Box::new(SimpleResult::error(
+            "Panic in simple_lancedb_table_poll_version".to_string(),
+        ))),
+    }
+}
+
+/// Get table schema as JSON (simple version)
+#[no_mangle]
+pub extern "C" fn simple_lancedb_table_schema(
+    table_handle: *mut c_void,
+    schema_json: *mut *mut c_char,
+) -> *mut SimpleResult {
+    let result = std::panic::catch_unwind(|| -> SimpleResult {
+        if table_handle.is_null() || schema_json.is_null() {
+            return SimpleResult::error("Invalid null arguments".to_string());
+        }
+
+        let table = unsafe { &*(table_handle as *const lancedb::Table) };
+        let rt = get_simple_runtime();
+
+        match rt.block_on(async { table.schema().await }) {
+            Ok(arrow_schema) => {
+                let schema_json_obj = arrow_schema_to_json(&arrow_schema);
 
                 match serde_json::to_string(&schema_json_obj) {
                     Ok(json_str) => {
@@ -140,7 +297,7 @@ pub extern "C" fn simple_lancedb_table_schema(
                     Err(e) => SimpleResult::error(format!("Failed to serialize schema: {}", e)),
                 }
             }
-            Err(e) => SimpleResult::error(format!("Failed to get table schema: {}", e)),
+            Err(e) => SimpleResult::from_lancedb_error("Failed to get table schema", e),
         }
     });
 
@@ -192,7 +349,7 @@ pub extern "C" fn simple_lancedb_table_schema_ipc(
                     Err(e) => SimpleResult::error(format!("Failed to serialize schema to IPC: {}", e)),
                 }
             }
-            Err(e) => SimpleResult::error(format!("Failed to get table schema: {}", e)),
+            Err(e) => SimpleResult::from_lancedb_error("Failed to get table schema", e),
         }
     });
 
@@ -3,14 +3,21 @@
 
 //! Query and search operations
 
-use crate::conversion::convert_arrow_value_to_json;
-use crate::ffi::{from_c_str, SimpleResult};
+use crate::conversion::convert_arrow_value_to_json_for_field;
+use crate::ffi::{from_c_str, SimpleIpcChunkCallback, SimpleResult};
 use crate::runtime::get_simple_runtime;
-use lancedb::query::{ExecutableQuery, QueryBase};
+use arrow_ipc::writer::StreamWriter;
+use lancedb::arrow::SendableRecordBatchStream;
+use lancedb::query::{ExecutableQuery, FullTextSearchQuery, QueryBase};
+use std::collections::HashMap;
 use std::ffi::CString;
 use std::os::raw::{c_char, c_void};
 use tokio_stream::StreamExt;
 
+/// Default Reciprocal Rank Fusion constant, matching the common `k = 60` default
+/// used when combining vector and full-text search result rankings.
+const DEFAULT_RRF_K: f64 = 60.0;
+
 /// Execute a select query with various predicates (vector search, filters, etc.)
 #[no_mangle]
 #[allow(clippy::not_unsafe_ptr_arg_deref)]
@@ -38,16 +45,546 @@ pub extern "C" fn simple_lancedb_table_select_query(
             Err(e) => return SimpleResult::error(format!("Failed to parse query config: {}", e)),
         };
 
-        // Execute query based on configuration
-        match rt.block_on(async {
-            // Check if this is a vector search query first, as it needs special handling
+        match rt.block_on(execute_query_config(table, &query_config)) {
+            Ok(results) => match serde_json::to_string(&results) {
+                Ok(json_str) => match CString::new(json_str) {
+                    Ok(c_string) => {
+                        unsafe {
+                            *result_json = c_string.into_raw();
+                        }
+                        SimpleResult::ok()
+                    }
+                    Err(_) => {
+                        SimpleResult::error("Failed to convert results to C string".to_string())
+                    }
+                },
+                Err(e) => {
+                    SimpleResult::error(format!("Failed to serialize results to JSON: {}", e))
+                }
+            },
+            Err(e) => SimpleResult::error(format!("Failed to execute query: {}", e)),
+        }
+    });
+
+    match result {
+        Ok(res) => Box::into_raw(Box::new(res)),
+        Err(_) => Box::into_raw(Box::new(SimpleResult::error(
+            "Panic in simple_lancedb_table_select_query".to_string(),
+        ))),
+    }
+}
+
+/// Execute a single `simple_lancedb_table_select_query`-style config JSON and
+/// return its rows. Shared by the single-query and batch-query FFI entry
+/// points so both go through identical vector/FTS/hybrid/plain-query logic.
+async fn execute_query_config(
+    table: &lancedb::Table,
+    query_config: &serde_json::Value,
+) -> Result<Vec<serde_json::Value>, String> {
+    // Hybrid search combines a vector query and an FTS query via Reciprocal
+    // Rank Fusion, so it builds its own result set rather than going through
+    // the single-RecordBatchReader path below.
+    if let Some(hybrid_search) = query_config.get("hybrid_search") {
+        return execute_hybrid_search(table, query_config, hybrid_search).await;
+    }
+
+    let stream_result = async {
+        // Check if this is a vector search query first, as it needs special handling
+        if let Some(vector_search) = query_config.get("vector_search") {
+            if let (Some(column), Some(vector_values), Some(k)) = (
+                vector_search.get("column").and_then(|v| v.as_str()),
+                vector_search.get("vector").and_then(|v| v.as_array()),
+                vector_search.get("k").and_then(|v| v.as_u64()),
+            ) {
+                // Convert JSON array to Vec<f32>
+                let vector: Result<Vec<f32>, String> = vector_values
+                    .iter()
+                    .map(|v| {
+                        v.as_f64()
+                            .map(|f| f as f32)
+                            .ok_or_else(|| "Invalid vector element".to_string())
+                    })
+                    .collect();
+
+                match vector {
+                    Ok(vec) => {
+                        // Use the limit from query config, or k if not specified
+                        let effective_limit = query_config
+                            .get("limit")
+                            .and_then(|v| v.as_u64())
+                            .map(|l| l as usize)
+                            .unwrap_or(k as usize);
+
+                        let mut vector_query = table
+                            .query()
+                            .nearest_to(vec)?
+                            .column(column)
+                            .limit(effective_limit);
+
+                        // Apply WHERE filter for vector queries
+                        if let Some(filter) = query_config.get("where").and_then(|v| v.as_str()) {
+                            vector_query = vector_query.only_if(filter);
+                        }
+
+                        // Apply column selection for vector queries
+                        if let Some(columns) =
+                            query_config.get("columns").and_then(|v| v.as_array())
+                        {
+                            let column_names: Vec<String> = columns
+                                .iter()
+                                .filter_map(|v| v.as_str())
+                                .map(|s| s.to_string())
+                                .collect();
+                            if !column_names.is_empty() {
+                                vector_query = vector_query
+                                    .select(lancedb::query::Select::Columns(column_names));
+                            }
+                        }
+
+                        return vector_query.execute().await;
+                    }
+                    Err(e) => {
+                        return Err(lancedb::Error::InvalidInput {
+                            message: format!("Failed to parse vector: {}", e),
+                        })
+                    }
+                }
+            }
+        }
+
+        // Apply full-text search
+        if let Some(fts_search) = query_config.get("fts_search") {
+            if let (Some(column), Some(query_text)) = (
+                fts_search.get("column").and_then(|v| v.as_str()),
+                fts_search.get("query").and_then(|v| v.as_str()),
+            ) {
+                let mut fts_query = table.query().full_text_search(
+                    FullTextSearchQuery::new(query_text.to_string())
+                        .columns(vec![column.to_string()]),
+                );
+
+                let effective_limit = fts_search
+                    .get("limit")
+                    .and_then(|v| v.as_u64())
+                    .or_else(|| query_config.get("limit").and_then(|v| v.as_u64()));
+                if let Some(limit) = effective_limit {
+                    fts_query = fts_query.limit(limit as usize);
+                }
+
+                if let Some(filter) = query_config.get("where").and_then(|v| v.as_str()) {
+                    fts_query = fts_query.only_if(filter);
+                }
+
+                return fts_query.execute().await;
+            }
+        }
+
+        // For non-vector queries, use regular query
+        let mut query = table.query();
+
+        // Apply column selection
+        if let Some(columns) = query_config.get("columns").and_then(|v| v.as_array()) {
+            let column_names: Vec<String> = columns
+                .iter()
+                .filter_map(|v| v.as_str())
+                .map(|s| s.to_string())
+                .collect();
+            if !column_names.is_empty() {
+                query = query.select(lancedb::query::Select::Columns(column_names));
+            }
+        }
+
+        // Apply limit
+        if let Some(limit) = query_config.get("limit").and_then(|v| v.as_u64()) {
+            query = query.limit(limit as usize);
+        }
+
+        // Apply offset
+        if let Some(offset) = query_config.get("offset").and_then(|v| v.as_u64()) {
+            query = query.offset(offset as usize);
+        }
+
+        // Apply WHERE filter
+        if let Some(filter) = query_config.get("where").and_then(|v| v.as_str()) {
+            query = query.only_if(filter);
+        }
+
+        // Execute the query
+        query.execute().await
+    }
+    .await;
+
+    match stream_result {
+        Ok(record_batch_reader) => stream_to_json_rows(record_batch_reader)
+            .await
+            .map_err(|e| format!("Failed to process query results: {}", e)),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Drain a batch stream into the flat JSON-row shape the select-query FFI
+/// entry points return to Go callers.
+async fn stream_to_json_rows(
+    mut stream: SendableRecordBatchStream,
+) -> Result<Vec<serde_json::Value>, lancedb::Error> {
+    let mut results = Vec::new();
+    while let Some(batch_result) = stream.next().await {
+        let batch = batch_result?;
+        let schema = batch.schema();
+        for row_idx in 0..batch.num_rows() {
+            let mut row = serde_json::Map::new();
+            for (col_idx, field) in schema.fields().iter().enumerate() {
+                let column = batch.column(col_idx);
+                let json_value = match convert_arrow_value_to_json_for_field(column, row_idx, field)
+                {
+                    Ok(v) => v,
+                    Err(_) => serde_json::Value::Null,
+                };
+                row.insert(field.name().clone(), json_value);
+            }
+            results.push(serde_json::Value::Object(row));
+        }
+    }
+    Ok(results)
+}
+
+/// Execute many select-query configs concurrently on the shared runtime in
+/// one FFI call, so N small queries against the same table share one cgo
+/// crossing and overlap their I/O instead of N blocking round-trips.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn simple_lancedb_table_select_batch(
+    table_handle: *mut c_void,
+    batch_config_json: *const c_char,
+    result_json: *mut *mut c_char,
+) -> *mut SimpleResult {
+    let result = std::panic::catch_unwind(|| -> SimpleResult {
+        if table_handle.is_null() || batch_config_json.is_null() || result_json.is_null() {
+            return SimpleResult::error("Invalid null arguments".to_string());
+        }
+
+        let config_str = match from_c_str(batch_config_json) {
+            Ok(s) => s,
+            Err(e) => return SimpleResult::error(format!("Invalid batch config JSON: {}", e)),
+        };
+
+        let query_configs: Vec<serde_json::Value> = match serde_json::from_str(&config_str) {
+            Ok(serde_json::Value::Array(arr)) => arr,
+            Ok(_) => return SimpleResult::error("Batch config JSON must be an array".to_string()),
+            Err(e) => return SimpleResult::error(format!("Failed to parse batch config: {}", e)),
+        };
+
+        let table = unsafe { &*(table_handle as *const lancedb::Table) };
+        let rt = get_simple_runtime();
+
+        let batch_results: Vec<serde_json::Value> = rt.block_on(async {
+            let futures = query_configs
+                .iter()
+                .map(|cfg| execute_query_config(table, cfg));
+            futures::future::join_all(futures)
+                .await
+                .into_iter()
+                .map(|res| match res {
+                    Ok(rows) => serde_json::json!({ "rows": rows, "error": null }),
+                    Err(e) => serde_json::json!({ "rows": [], "error": e }),
+                })
+                .collect()
+        });
+
+        match serde_json::to_string(&batch_results) {
+            Ok(json_str) => match CString::new(json_str) {
+                Ok(c_string) => {
+                    unsafe {
+                        *result_json = c_string.into_raw();
+                    }
+                    SimpleResult::ok()
+                }
+                Err(_) => SimpleResult::error("Failed to convert results to C string".to_string()),
+            },
+            Err(e) => SimpleResult::error(format!("Failed to serialize results to JSON: {}", e)),
+        }
+    });
+
+    match result {
+        Ok(res) => Box::into_raw(Box::new(res)),
+        Err(_) => Box::into_raw(Box::new(SimpleResult::error(
+            "Panic in simple_lancedb_table_select_batch".to_string(),
+        ))),
+    }
+}
+
+/// Run the vector and FTS sub-queries of a `hybrid_search` config and fuse
+/// their rankings with Reciprocal Rank Fusion.
+async fn execute_hybrid_search(
+    table: &lancedb::Table,
+    query_config: &serde_json::Value,
+    hybrid_search: &serde_json::Value,
+) -> Result<Vec<serde_json::Value>, String> {
+    let vector_cfg = hybrid_search
+        .get("vector")
+        .ok_or("hybrid_search requires a 'vector' sub-config")?;
+    let fts_cfg = hybrid_search
+        .get("fts")
+        .ok_or("hybrid_search requires an 'fts' sub-config")?;
+
+    let limit = hybrid_search
+        .get("limit")
+        .and_then(|v| v.as_u64())
+        .or_else(|| query_config.get("limit").and_then(|v| v.as_u64()))
+        .unwrap_or(10) as usize;
+    let rrf_k = hybrid_search
+        .get("rrf_k")
+        .and_then(|v| v.as_f64())
+        .unwrap_or(DEFAULT_RRF_K);
+
+    let column = vector_cfg
+        .get("column")
+        .and_then(|v| v.as_str())
+        .ok_or("vector config requires a 'column'")?;
+    let vector_values = vector_cfg
+        .get("vector")
+        .and_then(|v| v.as_array())
+        .ok_or("vector config requires a 'vector' array")?;
+    let vector: Vec<f32> = vector_values
+        .iter()
+        .map(|v| {
+            v.as_f64()
+                .map(|f| f as f32)
+                .ok_or_else(|| "Invalid vector element".to_string())
+        })
+        .collect::<Result<_, String>>()?;
+    let vector_limit = vector_cfg
+        .get("k")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(limit as u64) as usize;
+
+    let fts_column = fts_cfg
+        .get("column")
+        .and_then(|v| v.as_str())
+        .ok_or("fts config requires a 'column'")?;
+    let fts_text = fts_cfg
+        .get("query")
+        .and_then(|v| v.as_str())
+        .ok_or("fts config requires a 'query'")?;
+    let fts_limit = fts_cfg
+        .get("limit")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(limit as u64) as usize;
+
+    let vector_rows = {
+        let stream = table
+            .query()
+            .nearest_to(vector)
+            .map_err(|e| e.to_string())?
+            .column(column)
+            .limit(vector_limit)
+            .with_row_id()
+            .execute()
+            .await
+            .map_err(|e| e.to_string())?;
+        stream_to_json_rows(stream)
+            .await
+            .map_err(|e| e.to_string())?
+    };
+
+    let fts_rows = {
+        let stream = table
+            .query()
+            .full_text_search(
+                FullTextSearchQuery::new(fts_text.to_string())
+                    .columns(vec![fts_column.to_string()]),
+            )
+            .limit(fts_limit)
+            .with_row_id()
+            .execute()
+            .await
+            .map_err(|e| e.to_string())?;
+        stream_to_json_rows(stream)
+            .await
+            .map_err(|e| e.to_string())?
+    };
+
+    Ok(fuse_rrf(&vector_rows, &fts_rows, rrf_k, limit))
+}
+
+/// Merge two ranked row lists keyed by `_rowid` with Reciprocal Rank Fusion:
+/// each row's fused score is `sum over lists of 1/(k + rank)`, with rows
+/// appearing in only one list still contributing their single rank. Rows with
+/// equal scores keep the order in which they are first seen (vector list
+/// first, then full-text list).
+fn fuse_rrf(
+    vector_rows: &[serde_json::Value],
+    fts_rows: &[serde_json::Value],
+    k: f64,
+    limit: usize,
+) -> Vec<serde_json::Value> {
+    let mut scores: HashMap<i64, f64> = HashMap::new();
+    let mut rows: HashMap<i64, serde_json::Value> = HashMap::new();
+    let mut first_seen: Vec<i64> = Vec::new();
+
+    for list in [vector_rows, fts_rows] {
+        for (idx, row) in list.iter().enumerate() {
+            let rank = (idx + 1) as f64;
+            if let Some(rowid) = row.get("_rowid").and_then(|v| v.as_i64()) {
+                if !scores.contains_key(&rowid) {
+                    first_seen.push(rowid);
+                }
+                *scores.entry(rowid).or_insert(0.0) += 1.0 / (k + rank);
+                rows.entry(rowid).or_insert_with(|| row.clone());
+            }
+        }
+    }
+
+    // `sort_by` is stable, so ties stay in first-seen order.
+    let mut scored: Vec<(i64, f64)> = first_seen
+        .into_iter()
+        .map(|rowid| (rowid, scores[&rowid]))
+        .collect();
+    scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    scored.truncate(limit);
+
+    scored
+        .into_iter()
+        .filter_map(|(rowid, score)| {
+            rows.remove(&rowid).map(|mut row| {
+                if let serde_json::Value::Object(ref mut map) = row {
+                    map.insert("_relevance_score".to_string(), serde_json::json!(score));
+                }
+                row
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversion::convert_arrow_value_to_json;
+
+    // `with_row_id()` attaches a `UInt64` `_rowid` column, so rows must be
+    // built through the real JSON converter rather than hand-written
+    // `serde_json::json!` values - that's what caught the original bug,
+    // where `_rowid` serialized as the string "Unsupported type: UInt64"
+    // instead of a number and `fuse_rrf` silently dropped every row.
+    fn row_with_rowid(rowid: u64, extra: &str) -> serde_json::Value {
+        let rowid_array = arrow_array::UInt64Array::from(vec![rowid]);
+        let rowid_json = convert_arrow_value_to_json(&rowid_array, 0).unwrap();
+        assert!(
+            rowid_json.is_number(),
+            "UInt64 _rowid must convert to a JSON number, got {:?}",
+            rowid_json
+        );
+
+        serde_json::json!({ "_rowid": rowid_json, "text": extra })
+    }
+
+    #[test]
+    fn fuse_rrf_combines_ranks_from_both_lists() {
+        let vector_rows = vec![row_with_rowid(1, "a"), row_with_rowid(2, "b")];
+        let fts_rows = vec![row_with_rowid(2, "b"), row_with_rowid(3, "c")];
+
+        let fused = fuse_rrf(&vector_rows, &fts_rows, DEFAULT_RRF_K, 10);
+
+        // Row 2 appears in both lists, so it must outrank rows seen once.
+        assert_eq!(fused.len(), 3);
+        assert_eq!(fused[0]["_rowid"], serde_json::json!(2));
+        let rowids: Vec<u64> = fused
+            .iter()
+            .map(|r| r["_rowid"].as_u64().unwrap())
+            .collect();
+        assert!(rowids.contains(&1));
+        assert!(rowids.contains(&3));
+    }
+
+    #[test]
+    fn fuse_rrf_respects_limit() {
+        let vector_rows = vec![row_with_rowid(1, "a"), row_with_rowid(2, "b")];
+        let fts_rows = vec![row_with_rowid(3, "c")];
+
+        let fused = fuse_rrf(&vector_rows, &fts_rows, DEFAULT_RRF_K, 1);
+
+        assert_eq!(fused.len(), 1);
+        assert_eq!(fused[0]["_rowid"], serde_json::json!(1));
+    }
+}
+
+/// An in-progress query result that hands back one Arrow IPC message at a time
+/// instead of materializing the whole result set.
+struct SimpleQueryStream {
+    stream: SendableRecordBatchStream,
+    writer: StreamWriter<Vec<u8>>,
+    schema_sent: bool,
+    finished: bool,
+}
+
+/// Take whatever bytes the writer has accumulated since the last drain
+fn drain_written(writer: &mut StreamWriter<Vec<u8>>) -> Vec<u8> {
+    std::mem::take(writer.get_mut())
+}
+
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+fn write_out_bytes(bytes: Vec<u8>, out_bytes: *mut *mut u8, out_len: *mut usize) -> SimpleResult {
+    let len = bytes.len();
+    if len == 0 {
+        unsafe {
+            *out_bytes = std::ptr::null_mut();
+            *out_len = 0;
+        }
+        return SimpleResult::ok();
+    }
+
+    let data_ptr = unsafe { libc::malloc(len) as *mut u8 };
+    if data_ptr.is_null() {
+        return SimpleResult::error("Failed to allocate memory for IPC batch data".to_string());
+    }
+
+    unsafe {
+        std::ptr::copy_nonoverlapping(bytes.as_ptr(), data_ptr, len);
+        *out_bytes = data_ptr;
+        *out_len = len;
+    }
+    SimpleResult::ok()
+}
+
+/// Begin a streaming select query, returning an opaque handle that
+/// `simple_lancedb_stream_next_batch` can be polled against.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn simple_lancedb_table_select_query_stream(
+    table_handle: *mut c_void,
+    query_config_json: *const c_char,
+    stream_handle: *mut *mut c_void,
+) -> *mut SimpleResult {
+    let result = std::panic::catch_unwind(|| -> SimpleResult {
+        if table_handle.is_null() || query_config_json.is_null() || stream_handle.is_null() {
+            return SimpleResult::error("Invalid null arguments".to_string());
+        }
+
+        let config_str = match from_c_str(query_config_json) {
+            Ok(s) => s,
+            Err(e) => return SimpleResult::error(format!("Invalid query config JSON: {}", e)),
+        };
+
+        let table = unsafe { &*(table_handle as *const lancedb::Table) };
+        let rt = get_simple_runtime();
+
+        let query_config: serde_json::Value = match serde_json::from_str(&config_str) {
+            Ok(config) => config,
+            Err(e) => return SimpleResult::error(format!("Failed to parse query config: {}", e)),
+        };
+
+        let schema = match rt.block_on(async { table.schema().await }) {
+            Ok(schema) => schema,
+            Err(e) => return SimpleResult::from_lancedb_error("Failed to get table schema", e),
+        };
+
+        let stream_result = rt.block_on(async {
             if let Some(vector_search) = query_config.get("vector_search") {
                 if let (Some(column), Some(vector_values), Some(k)) = (
                     vector_search.get("column").and_then(|v| v.as_str()),
                     vector_search.get("vector").and_then(|v| v.as_array()),
                     vector_search.get("k").and_then(|v| v.as_u64()),
                 ) {
-                    // Convert JSON array to Vec<f32>
                     let vector: Result<Vec<f32>, String> = vector_values
                         .iter()
                         .map(|v| {
@@ -57,71 +594,49 @@ pub extern "C" fn simple_lancedb_table_select_query(
                         })
                         .collect();
 
-                    match vector {
-                        Ok(vec) => {
-                            // Use the limit from query config, or k if not specified
-                            let effective_limit = query_config
-                                .get("limit")
-                                .and_then(|v| v.as_u64())
-                                .map(|l| l as usize)
-                                .unwrap_or(k as usize);
-
-                            let mut vector_query = table
-                                .query()
-                                .nearest_to(vec)?
-                                .column(column)
-                                .limit(effective_limit);
-
-                            // Apply WHERE filter for vector queries
-                            if let Some(filter) = query_config.get("where").and_then(|v| v.as_str())
-                            {
-                                vector_query = vector_query.only_if(filter);
-                            }
-
-                            // Apply column selection for vector queries
-                            if let Some(columns) =
-                                query_config.get("columns").and_then(|v| v.as_array())
-                            {
-                                let column_names: Vec<String> = columns
-                                    .iter()
-                                    .filter_map(|v| v.as_str())
-                                    .map(|s| s.to_string())
-                                    .collect();
-                                if !column_names.is_empty() {
-                                    vector_query = vector_query
-                                        .select(lancedb::query::Select::Columns(column_names));
-                                }
-                            }
-
-                            return vector_query.execute().await;
-                        }
+                    let vector = match vector {
+                        Ok(vec) => vec,
                         Err(e) => {
                             return Err(lancedb::Error::InvalidInput {
                                 message: format!("Failed to parse vector: {}", e),
                             })
                         }
+                    };
+
+                    let effective_limit = query_config
+                        .get("limit")
+                        .and_then(|v| v.as_u64())
+                        .map(|l| l as usize)
+                        .unwrap_or(k as usize);
+
+                    let mut vector_query = table
+                        .query()
+                        .nearest_to(vector)?
+                        .column(column)
+                        .limit(effective_limit);
+
+                    if let Some(filter) = query_config.get("where").and_then(|v| v.as_str()) {
+                        vector_query = vector_query.only_if(filter);
                     }
-                }
-            }
 
-            // Apply full-text search
-            if let Some(fts_search) = query_config.get("fts_search") {
-                if let (Some(_column), Some(_query_text)) = (
-                    fts_search.get("column").and_then(|v| v.as_str()),
-                    fts_search.get("query").and_then(|v| v.as_str()),
-                ) {
-                    // Note: FTS search is not currently available in this API version
-                    // This is a placeholder for future implementation
-                    return Err(lancedb::Error::InvalidInput {
-                        message: "Full-text search is not currently supported".to_string(),
-                    });
+                    if let Some(columns) = query_config.get("columns").and_then(|v| v.as_array()) {
+                        let column_names: Vec<String> = columns
+                            .iter()
+                            .filter_map(|v| v.as_str())
+                            .map(|s| s.to_string())
+                            .collect();
+                        if !column_names.is_empty() {
+                            vector_query =
+                                vector_query.select(lancedb::query::Select::Columns(column_names));
+                        }
+                    }
+
+                    return vector_query.execute().await;
                 }
             }
 
-            // For non-vector queries, use regular query
             let mut query = table.query();
 
-            // Apply column selection
             if let Some(columns) = query_config.get("columns").and_then(|v| v.as_array()) {
                 let column_names: Vec<String> = columns
                     .iter()
@@ -133,94 +648,294 @@ pub extern "C" fn simple_lancedb_table_select_query(
                 }
             }
 
-            // Apply limit
             if let Some(limit) = query_config.get("limit").and_then(|v| v.as_u64()) {
                 query = query.limit(limit as usize);
             }
 
-            // Apply offset
             if let Some(offset) = query_config.get("offset").and_then(|v| v.as_u64()) {
                 query = query.offset(offset as usize);
             }
 
-            // Apply WHERE filter
             if let Some(filter) = query_config.get("where").and_then(|v| v.as_str()) {
                 query = query.only_if(filter);
             }
 
-            // Execute the query
             query.execute().await
-        }) {
-            Ok(record_batch_reader) => {
-                // Convert RecordBatch results to JSON
-                let mut results = Vec::new();
-
-                // Note: This is a simplified approach. In a real implementation,
-                // you might want to stream results or handle large datasets differently.
-                match rt.block_on(async {
-                    let mut stream = record_batch_reader;
-                    while let Some(batch_result) = stream.next().await {
-                        match batch_result {
-                            Ok(batch) => {
-                                // Convert RecordBatch to JSON
-                                for row_idx in 0..batch.num_rows() {
-                                    let mut row = serde_json::Map::new();
-                                    let schema = batch.schema();
-
-                                    for (col_idx, field) in schema.fields().iter().enumerate() {
-                                        let column = batch.column(col_idx);
-                                        let field_name = field.name();
-
-                                        // Convert Arrow array value to JSON value
-                                        let json_value =
-                                            match convert_arrow_value_to_json(column, row_idx) {
-                                                Ok(v) => v,
-                                                Err(_) => serde_json::Value::Null,
-                                            };
-
-                                        row.insert(field_name.clone(), json_value);
-                                    }
-                                    results.push(serde_json::Value::Object(row));
-                                }
-                            }
-                            Err(e) => return Err(e),
-                        }
+        });
+
+        match stream_result {
+            Ok(stream) => match StreamWriter::try_new(Vec::new(), &schema) {
+                Ok(writer) => {
+                    let handle = Box::new(SimpleQueryStream {
+                        stream,
+                        writer,
+                        schema_sent: false,
+                        finished: false,
+                    });
+                    unsafe {
+                        *stream_handle = Box::into_raw(handle) as *mut c_void;
                     }
-                    Ok(())
-                }) {
-                    Ok(()) => {
-                        // Serialize results to JSON
-                        match serde_json::to_string(&results) {
-                            Ok(json_str) => match CString::new(json_str) {
-                                Ok(c_string) => {
-                                    unsafe {
-                                        *result_json = c_string.into_raw();
-                                    }
-                                    SimpleResult::ok()
-                                }
-                                Err(_) => SimpleResult::error(
-                                    "Failed to convert results to C string".to_string(),
-                                ),
-                            },
-                            Err(e) => SimpleResult::error(format!(
-                                "Failed to serialize results to JSON: {}",
+                    SimpleResult::ok()
+                }
+                Err(e) => SimpleResult::error(format!("Failed to start IPC stream: {}", e)),
+            },
+            Err(e) => SimpleResult::from_lancedb_error("Failed to execute query", e),
+        }
+    });
+
+    match result {
+        Ok(res) => Box::into_raw(Box::new(res)),
+        Err(_) => Box::into_raw(Box::new(SimpleResult::error(
+            "Panic in simple_lancedb_table_select_query_stream".to_string(),
+        ))),
+    }
+}
+
+/// Pull the next Arrow IPC message from a streaming query: the schema message
+/// on the first call, then one `RecordBatch` message per subsequent call, then
+/// the end-of-stream marker once the query has no more rows. An `out_len` of 0
+/// after that signals the stream is exhausted.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn simple_lancedb_stream_next_batch(
+    stream_handle: *mut c_void,
+    out_bytes: *mut *mut u8,
+    out_len: *mut usize,
+) -> *mut SimpleResult {
+    let result = std::panic::catch_unwind(|| -> SimpleResult {
+        if stream_handle.is_null() || out_bytes.is_null() || out_len.is_null() {
+            return SimpleResult::error("Invalid null arguments".to_string());
+        }
+
+        let handle = unsafe { &mut *(stream_handle as *mut SimpleQueryStream) };
+        let rt = get_simple_runtime();
+
+        if !handle.schema_sent {
+            handle.schema_sent = true;
+            let bytes = drain_written(&mut handle.writer);
+            return write_out_bytes(bytes, out_bytes, out_len);
+        }
+
+        if handle.finished {
+            return write_out_bytes(Vec::new(), out_bytes, out_len);
+        }
+
+        match rt.block_on(async { handle.stream.next().await }) {
+            Some(Ok(batch)) => {
+                if let Err(e) = handle.writer.write(&batch) {
+                    return SimpleResult::error(format!("Failed to write IPC batch: {}", e));
+                }
+                let bytes = drain_written(&mut handle.writer);
+                write_out_bytes(bytes, out_bytes, out_len)
+            }
+            Some(Err(e)) => SimpleResult::error(format!("Failed to read next batch: {}", e)),
+            None => {
+                handle.finished = true;
+                if let Err(e) = handle.writer.finish() {
+                    return SimpleResult::error(format!("Failed to finish IPC stream: {}", e));
+                }
+                let bytes = drain_written(&mut handle.writer);
+                write_out_bytes(bytes, out_bytes, out_len)
+            }
+        }
+    });
+
+    match result {
+        Ok(res) => Box::into_raw(Box::new(res)),
+        Err(_) => Box::into_raw(Box::new(SimpleResult::error(
+            "Panic in simple_lancedb_stream_next_batch".to_string(),
+        ))),
+    }
+}
+
+/// Close a streaming query handle, releasing the underlying query stream.
+#[no_mangle]
+pub extern "C" fn simple_lancedb_stream_close(stream_handle: *mut c_void) -> *mut SimpleResult {
+    if stream_handle.is_null() {
+        return Box::into_raw(Box::new(SimpleResult::error(
+            "Invalid null handle".to_string(),
+        )));
+    }
+
+    let result = std::panic::catch_unwind(|| -> SimpleResult {
+        unsafe {
+            let _stream = Box::from_raw(stream_handle as *mut SimpleQueryStream);
+            // Stream will be dropped here, cleaning up resources
+        }
+        SimpleResult::ok()
+    });
+
+    match result {
+        Ok(res) => Box::into_raw(Box::new(res)),
+        Err(_) => Box::into_raw(Box::new(SimpleResult::error(
+            "Panic in simple_lancedb_stream_close".to_string(),
+        ))),
+    }
+}
+
+/// Run a scan and serialize the resulting rows as Arrow IPC, giving Go
+/// callers zero-copy columnar access instead of the JSON row round-trip.
+///
+/// With `chunk_callback` null, the whole result set (schema message plus
+/// every `RecordBatch` message) is written into a single buffer malloc'd
+/// into `out_data`/`out_len`, to be freed with `simple_lancedb_free_ipc_data`.
+/// With `chunk_callback` set, `out_data`/`out_len` are left untouched and the
+/// callback receives the schema message, then one IPC message per batch as it
+/// is produced, then the end-of-stream marker; returning `false` from the
+/// callback aborts the scan.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn simple_lancedb_table_to_ipc_stream(
+    table_handle: *mut c_void,
+    filter: *const c_char,
+    columns_json: *const c_char,
+    limit: i64,
+    out_data: *mut *mut u8,
+    out_len: *mut usize,
+    chunk_callback: Option<SimpleIpcChunkCallback>,
+    chunk_ctx: *mut c_void,
+) -> *mut SimpleResult {
+    let result = std::panic::catch_unwind(|| -> SimpleResult {
+        if table_handle.is_null() {
+            return SimpleResult::error("Invalid null arguments".to_string());
+        }
+        if chunk_callback.is_none() && (out_data.is_null() || out_len.is_null()) {
+            return SimpleResult::error("Invalid null arguments".to_string());
+        }
+
+        let filter_str = if filter.is_null() {
+            None
+        } else {
+            match from_c_str(filter) {
+                Ok(s) => Some(s),
+                Err(e) => return SimpleResult::error(format!("Invalid filter: {}", e)),
+            }
+        };
+
+        let columns: Option<Vec<String>> = if columns_json.is_null() {
+            None
+        } else {
+            match from_c_str(columns_json) {
+                Ok(s) => match serde_json::from_str(&s) {
+                    Ok(cols) => Some(cols),
+                    Err(e) => {
+                        return SimpleResult::error(format!("Failed to parse columns JSON: {}", e))
+                    }
+                },
+                Err(e) => return SimpleResult::error(format!("Invalid columns JSON: {}", e)),
+            }
+        };
+
+        let table = unsafe { &*(table_handle as *const lancedb::Table) };
+        let rt = get_simple_runtime();
+
+        let stream = rt.block_on(async {
+            let mut query = table.query();
+
+            if let Some(column_names) = columns {
+                if !column_names.is_empty() {
+                    query = query.select(lancedb::query::Select::Columns(column_names));
+                }
+            }
+
+            if limit > 0 {
+                query = query.limit(limit as usize);
+            }
+
+            if let Some(filter_str) = &filter_str {
+                query = query.only_if(filter_str.as_str());
+            }
+
+            query.execute().await
+        });
+
+        let mut stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => return SimpleResult::from_lancedb_error("Failed to execute scan", e),
+        };
+
+        // The IPC schema is the scan result's (only the selected columns).
+        let mut writer = match StreamWriter::try_new(Vec::new(), &stream.schema()) {
+            Ok(writer) => writer,
+            Err(e) => return SimpleResult::error(format!("Failed to start IPC writer: {}", e)),
+        };
+
+        if let Some(callback) = chunk_callback {
+            let schema_message = drain_written(&mut writer);
+            if !invoke_chunk_callback(callback, &schema_message, chunk_ctx) {
+                return SimpleResult::error("cancelled".to_string());
+            }
+
+            loop {
+                match rt.block_on(async { stream.next().await }) {
+                    Some(Ok(batch)) => {
+                        if let Err(e) = writer.write(&batch) {
+                            return SimpleResult::error(format!(
+                                "Failed to write IPC batch: {}",
                                 e
-                            )),
+                            ));
+                        }
+                        let message = drain_written(&mut writer);
+                        if !invoke_chunk_callback(callback, &message, chunk_ctx) {
+                            return SimpleResult::error("cancelled".to_string());
                         }
                     }
-                    Err(e) => {
-                        SimpleResult::error(format!("Failed to process query results: {}", e))
+                    Some(Err(e)) => {
+                        return SimpleResult::error(format!("Failed to read next batch: {}", e))
                     }
+                    None => break,
                 }
             }
-            Err(e) => SimpleResult::error(format!("Failed to execute query: {}", e)),
+
+            if let Err(e) = writer.finish() {
+                return SimpleResult::error(format!("Failed to finish IPC stream: {}", e));
+            }
+            let trailer = drain_written(&mut writer);
+            if !trailer.is_empty() && !invoke_chunk_callback(callback, &trailer, chunk_ctx) {
+                return SimpleResult::error("cancelled".to_string());
+            }
+
+            return SimpleResult::ok();
+        }
+
+        loop {
+            match rt.block_on(async { stream.next().await }) {
+                Some(Ok(batch)) => {
+                    if let Err(e) = writer.write(&batch) {
+                        return SimpleResult::error(format!("Failed to write IPC batch: {}", e));
+                    }
+                }
+                Some(Err(e)) => {
+                    return SimpleResult::error(format!("Failed to read next batch: {}", e))
+                }
+                None => break,
+            }
         }
+
+        if let Err(e) = writer.finish() {
+            return SimpleResult::error(format!("Failed to finish IPC stream: {}", e));
+        }
+
+        write_out_bytes(drain_written(&mut writer), out_data, out_len)
     });
 
     match result {
         Ok(res) => Box::into_raw(Box::new(res)),
         Err(_) => Box::into_raw(Box::new(SimpleResult::error(
-            "Panic in simple_lancedb_table_select_query".to_string(),
+            "Panic in simple_lancedb_table_to_ipc_stream".to_string(),
         ))),
     }
 }
+
+/// Invoke a chunk callback with a message buffer's raw parts, treating an
+/// empty message (nothing new since the last drain) as a no-op success.
+fn invoke_chunk_callback(
+    callback: SimpleIpcChunkCallback,
+    message: &[u8],
+    ctx: *mut c_void,
+) -> bool {
+    if message.is_empty() {
+        return true;
+    }
+    callback(message.as_ptr(), message.len(), ctx)
+}
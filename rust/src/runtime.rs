@@ -3,17 +3,156 @@
 
 //! Runtime management for async operations
 
-use std::sync::{Arc, OnceLock};
-use tokio::runtime::Runtime;
+use crate::ffi::{from_c_str, SimpleResult};
+use std::os::raw::c_char;
+use std::sync::{Arc, Mutex};
+use tokio::runtime::{Builder, Runtime};
 
-/// Global runtime for async operations  
-static SIMPLE_RUNTIME: OnceLock<Arc<Runtime>> = OnceLock::new();
+/// Worker-pool sizing for the global runtime, applied the first time any FFI
+/// entry point runs. Set via `simple_lancedb_runtime_configure` before that.
+#[derive(Clone)]
+struct RuntimeConfig {
+    worker_threads: Option<usize>,
+    max_blocking_threads: Option<usize>,
+    thread_name: Option<String>,
+    enable_io: bool,
+    enable_time: bool,
+}
+
+impl Default for RuntimeConfig {
+    fn default() -> Self {
+        // Matches the multi-threaded, fully-enabled runtime `Runtime::new()` used
+        // to build prior to this module existing.
+        Self {
+            worker_threads: None,
+            max_blocking_threads: None,
+            thread_name: None,
+            enable_io: true,
+            enable_time: true,
+        }
+    }
+}
+
+/// Global runtime for async operations, lazily built on first use
+static SIMPLE_RUNTIME: Mutex<Option<Arc<Runtime>>> = Mutex::new(None);
+/// Configuration to apply the next time the runtime is (re)built
+static PENDING_CONFIG: Mutex<Option<RuntimeConfig>> = Mutex::new(None);
 
 pub fn get_simple_runtime() -> Arc<Runtime> {
-    SIMPLE_RUNTIME
-        .get_or_init(|| {
-            let rt = Runtime::new().expect("Failed to create tokio runtime");
-            Arc::new(rt)
-        })
-        .clone()
+    let mut runtime = SIMPLE_RUNTIME.lock().unwrap();
+    if let Some(rt) = runtime.as_ref() {
+        return rt.clone();
+    }
+
+    let config = PENDING_CONFIG.lock().unwrap().take().unwrap_or_default();
+    let rt = Arc::new(build_runtime(&config).expect("Failed to create tokio runtime"));
+    *runtime = Some(rt.clone());
+    rt
+}
+
+fn build_runtime(config: &RuntimeConfig) -> std::io::Result<Runtime> {
+    let mut builder = Builder::new_multi_thread();
+
+    if let Some(worker_threads) = config.worker_threads {
+        builder.worker_threads(worker_threads);
+    }
+    if let Some(max_blocking_threads) = config.max_blocking_threads {
+        builder.max_blocking_threads(max_blocking_threads);
+    }
+    if let Some(thread_name) = &config.thread_name {
+        builder.thread_name(thread_name.clone());
+    }
+    if config.enable_io {
+        builder.enable_io();
+    }
+    if config.enable_time {
+        builder.enable_time();
+    }
+
+    builder.build()
+}
+
+fn parse_runtime_config(config_json: &serde_json::Value) -> RuntimeConfig {
+    let defaults = RuntimeConfig::default();
+    RuntimeConfig {
+        worker_threads: config_json
+            .get("worker_threads")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as usize),
+        max_blocking_threads: config_json
+            .get("max_blocking_threads")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as usize),
+        thread_name: config_json
+            .get("thread_name")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        enable_io: config_json
+            .get("enable_io")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(defaults.enable_io),
+        enable_time: config_json
+            .get("enable_time")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(defaults.enable_time),
+    }
+}
+
+/// Configure the shared Tokio runtime's worker pool. Must be called before the
+/// first FFI call that touches the runtime (e.g. connect); once the runtime
+/// has been built, this returns an error instead of silently no-opping.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn simple_lancedb_runtime_configure(config_json: *const c_char) -> *mut SimpleResult {
+    let result = std::panic::catch_unwind(|| -> SimpleResult {
+        if config_json.is_null() {
+            return SimpleResult::error("Invalid null arguments".to_string());
+        }
+
+        let config_str = match from_c_str(config_json) {
+            Ok(s) => s,
+            Err(e) => return SimpleResult::error(format!("Invalid runtime config JSON: {}", e)),
+        };
+
+        let config_value: serde_json::Value = match serde_json::from_str(&config_str) {
+            Ok(v) => v,
+            Err(e) => return SimpleResult::error(format!("Failed to parse runtime config: {}", e)),
+        };
+
+        if SIMPLE_RUNTIME.lock().unwrap().is_some() {
+            return SimpleResult::error(
+                "Runtime already initialized; call simple_lancedb_runtime_configure before the first connect".to_string(),
+            );
+        }
+
+        *PENDING_CONFIG.lock().unwrap() = Some(parse_runtime_config(&config_value));
+        SimpleResult::ok()
+    });
+
+    match result {
+        Ok(res) => Box::into_raw(Box::new(res)),
+        Err(_) => Box::into_raw(Box::new(SimpleResult::error(
+            "Panic in simple_lancedb_runtime_configure".to_string(),
+        ))),
+    }
+}
+
+/// Shut down the shared Tokio runtime for clean teardown in tests or plugin
+/// unload. A subsequent call into the library rebuilds it from the config of
+/// a `simple_lancedb_runtime_configure` call made after the shutdown, or with
+/// defaults if there was none (the config in effect before the shutdown is
+/// not reused).
+#[no_mangle]
+pub extern "C" fn simple_lancedb_runtime_shutdown() -> *mut SimpleResult {
+    let result = std::panic::catch_unwind(|| -> SimpleResult {
+        *SIMPLE_RUNTIME.lock().unwrap() = None;
+        SimpleResult::ok()
+    });
+
+    match result {
+        Ok(res) => Box::into_raw(Box::new(res)),
+        Err(_) => Box::into_raw(Box::new(SimpleResult::error(
+            "Panic in simple_lancedb_runtime_shutdown".to_string(),
+        ))),
+    }
 }
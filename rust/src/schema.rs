@@ -3,7 +3,7 @@
 
 //! Schema operations and utilities
 
-use arrow_schema::{DataType, Field, Schema};
+use arrow_schema::{DataType, Field, Fields, TimeUnit};
 use std::sync::Arc;
 
 /// Helper function to create Arrow schema from JSON
@@ -15,123 +15,939 @@ pub fn create_arrow_schema_from_json(
         .and_then(|f| f.as_array())
         .ok_or("Schema JSON must have 'fields' array")?;
 
-    let mut fields = Vec::new();
-
-    for field_json in fields_array {
-        let name = field_json
-            .get("name")
-            .and_then(|n| n.as_str())
-            .ok_or("Field must have 'name' string")?
-            .to_string();
-
-        let type_str = field_json
-            .get("type")
-            .and_then(|t| t.as_str())
-            .ok_or("Field must have 'type' string")?;
-
-        let nullable = field_json
-            .get("nullable")
-            .and_then(|n| n.as_bool())
-            .unwrap_or(true);
-
-        let data_type = match type_str {
-            "int8" => DataType::Int8,
-            "int16" => DataType::Int16,
-            "int32" => DataType::Int32,
-            "int64" => DataType::Int64,
-            "float16" => DataType::Float16,
-            "float32" => DataType::Float32,
-            "float64" => DataType::Float64,
-            "string" => DataType::Utf8,
-            "binary" => DataType::Binary,
-            "boolean" => DataType::Boolean,
-            _ => {
-                // Check for vector type
-                if type_str.starts_with("fixed_size_list[int8;") {
-                    let dimension_str = type_str
-                        .trim_start_matches("fixed_size_list[int8;")
-                        .trim_end_matches(']');
-                    let dimension: i32 = dimension_str
-                        .parse()
-                        .map_err(|_| format!("Invalid vector dimension: {}", dimension_str))?;
-                    DataType::FixedSizeList(
-                        Arc::new(Field::new("item", DataType::Int8, false)),
-                        dimension,
-                    )
-                } else if type_str.starts_with("fixed_size_list[int16;") {
-                    let dimension_str = type_str
-                        .trim_start_matches("fixed_size_list[int16;")
-                        .trim_end_matches(']');
-                    let dimension: i32 = dimension_str
-                        .parse()
-                        .map_err(|_| format!("Invalid vector dimension: {}", dimension_str))?;
-                    DataType::FixedSizeList(
-                        Arc::new(Field::new("item", DataType::Int16, false)),
-                        dimension,
-                    )
-                } else if type_str.starts_with("fixed_size_list[int32;") {
-                    let dimension_str = type_str
-                        .trim_start_matches("fixed_size_list[int32;")
-                        .trim_end_matches(']');
-                    let dimension: i32 = dimension_str
-                        .parse()
-                        .map_err(|_| format!("Invalid vector dimension: {}", dimension_str))?;
-                    DataType::FixedSizeList(
-                        Arc::new(Field::new("item", DataType::Int32, false)),
-                        dimension,
-                    )
-                } else if type_str.starts_with("fixed_size_list[int64;") {
-                    let dimension_str = type_str
-                        .trim_start_matches("fixed_size_list[int64;")
-                        .trim_end_matches(']');
-                    let dimension: i32 = dimension_str
-                        .parse()
-                        .map_err(|_| format!("Invalid vector dimension: {}", dimension_str))?;
-                    DataType::FixedSizeList(
-                        Arc::new(Field::new("item", DataType::Int64, false)),
-                        dimension,
-                    )
-                } else if type_str.starts_with("fixed_size_list[float16;") {
-                    let dimension_str = type_str
-                        .trim_start_matches("fixed_size_list[float16;")
-                        .trim_end_matches(']');
-                    let dimension: i32 = dimension_str
-                        .parse()
-                        .map_err(|_| format!("Invalid vector dimension: {}", dimension_str))?;
-                    DataType::FixedSizeList(
-                        Arc::new(Field::new("item", DataType::Float16, false)),
-                        dimension,
-                    )
-                } else if type_str.starts_with("fixed_size_list[float32;") {
-                    let dimension_str = type_str
-                        .trim_start_matches("fixed_size_list[float32;")
-                        .trim_end_matches(']');
-                    let dimension: i32 = dimension_str
-                        .parse()
-                        .map_err(|_| format!("Invalid vector dimension: {}", dimension_str))?;
-                    DataType::FixedSizeList(
-                        Arc::new(Field::new("item", DataType::Float32, false)),
-                        dimension,
-                    )
-                } else if type_str.starts_with("fixed_size_list[float64;") {
-                    let dimension_str = type_str
-                        .trim_start_matches("fixed_size_list[float64;")
-                        .trim_end_matches(']');
-                    let dimension: i32 = dimension_str
-                        .parse()
-                        .map_err(|_| format!("Invalid vector dimension: {}", dimension_str))?;
-                    DataType::FixedSizeList(
-                        Arc::new(Field::new("item", DataType::Float64, false)),
-                        dimension,
-                    )
-                } else {
-                    return Err(format!("Unsupported data type: {}", type_str).into());
+    Ok(arrow_schema::Schema::new(parse_fields_array(fields_array)?))
+}
+
+/// Render an Arrow `Schema` as the full, round-trippable JSON descriptor
+/// understood by `create_arrow_schema_from_json` - the inverse of
+/// `parse_fields_array`.
+pub fn arrow_schema_to_json(schema: &arrow_schema::Schema) -> serde_json::Value {
+    let fields: Vec<serde_json::Value> = schema.fields().iter().map(|f| field_to_json(f)).collect();
+    serde_json::json!({ "fields": fields })
+}
+
+/// Render a single `Field` as `{"name", "type", "nullable", "metadata"}`,
+/// recursing into nested types the same way `parse_field` recurses when
+/// reading them back.
+pub fn field_to_json(field: &Field) -> serde_json::Value {
+    let mut json = serde_json::json!({
+        "name": field.name(),
+        "type": data_type_to_json(field.data_type()),
+        "nullable": field.is_nullable(),
+    });
+    if !field.metadata().is_empty() {
+        json["metadata"] = serde_json::json!(field.metadata());
+    }
+    json
+}
+
+/// Render a `DataType` as the type-string/type-object grammar
+/// `parse_data_type_value` accepts, the inverse of that function.
+pub fn data_type_to_json(data_type: &DataType) -> serde_json::Value {
+    match data_type {
+        DataType::Int8 => serde_json::json!("int8"),
+        DataType::Int16 => serde_json::json!("int16"),
+        DataType::Int32 => serde_json::json!("int32"),
+        DataType::Int64 => serde_json::json!("int64"),
+        DataType::Float16 => serde_json::json!("float16"),
+        DataType::Float32 => serde_json::json!("float32"),
+        DataType::Float64 => serde_json::json!("float64"),
+        DataType::Utf8 => serde_json::json!("string"),
+        DataType::LargeUtf8 => serde_json::json!("large_string"),
+        DataType::Binary => serde_json::json!("binary"),
+        DataType::LargeBinary => serde_json::json!("large_binary"),
+        DataType::Boolean => serde_json::json!("boolean"),
+        DataType::Date32 => serde_json::json!("date32"),
+        DataType::Date64 => serde_json::json!("date64"),
+        DataType::Time32(unit) => serde_json::json!(format!("time32[{}]", time_unit_str(unit))),
+        DataType::Time64(unit) => serde_json::json!(format!("time64[{}]", time_unit_str(unit))),
+        DataType::Timestamp(unit, tz) => serde_json::json!(match tz {
+            Some(tz) => format!("timestamp[{},{}]", time_unit_str(unit), tz),
+            None => format!("timestamp[{}]", time_unit_str(unit)),
+        }),
+        DataType::Decimal128(precision, scale) => {
+            serde_json::json!(format!("decimal128({},{})", precision, scale))
+        }
+        DataType::Decimal256(precision, scale) => {
+            serde_json::json!(format!("decimal256({},{})", precision, scale))
+        }
+        DataType::FixedSizeList(item, size) => serde_json::json!({
+            "fixed_size_list": {
+                "item": data_type_to_json(item.data_type()),
+                "size": size,
+            }
+        }),
+        DataType::List(item) => serde_json::json!({ "list": data_type_to_json(item.data_type()) }),
+        DataType::LargeList(item) => {
+            serde_json::json!({ "large_list": data_type_to_json(item.data_type()) })
+        }
+        DataType::Struct(fields) => {
+            let nested: Vec<serde_json::Value> = fields.iter().map(|f| field_to_json(f)).collect();
+            serde_json::json!({ "struct": nested })
+        }
+        DataType::Map(entries, _sorted) => {
+            let DataType::Struct(entry_fields) = entries.data_type() else {
+                return serde_json::json!("unknown");
+            };
+            let Some(key_field) = entry_fields.iter().find(|f| f.name() == "key") else {
+                return serde_json::json!("unknown");
+            };
+            let Some(value_field) = entry_fields.iter().find(|f| f.name() == "value") else {
+                return serde_json::json!("unknown");
+            };
+            serde_json::json!({
+                "map": {
+                    "key": data_type_to_json(key_field.data_type()),
+                    "value": data_type_to_json(value_field.data_type()),
+                }
+            })
+        }
+        DataType::Dictionary(key, value) => serde_json::json!({
+            "dictionary": {
+                "key": data_type_to_json(key),
+                "value": data_type_to_json(value),
+            }
+        }),
+        other => serde_json::json!(format!("unsupported:{:?}", other)),
+    }
+}
+
+fn time_unit_str(unit: &TimeUnit) -> &'static str {
+    match unit {
+        TimeUnit::Second => "s",
+        TimeUnit::Millisecond => "ms",
+        TimeUnit::Microsecond => "us",
+        TimeUnit::Nanosecond => "ns",
+    }
+}
+
+/// Render an Arrow `Schema` using the canonical Arrow JSON integration-format
+/// type representation (`{"name":"int","bitWidth":32,"isSigned":true}`,
+/// `{"name":"floatingpoint","precision":"SINGLE"}`, ...) instead of the
+/// compact DSL `arrow_schema_to_json` produces. This is the shape pyarrow and
+/// arrow-cpp test harnesses expect, so it's the form to use when a schema
+/// needs to travel outside this crate.
+pub fn schema_to_json(schema: &arrow_schema::Schema) -> serde_json::Value {
+    let fields: Vec<serde_json::Value> = schema
+        .fields()
+        .iter()
+        .map(|f| integration_field_to_json(f))
+        .collect();
+    serde_json::json!({ "fields": fields })
+}
+
+/// Parse a schema in the canonical Arrow JSON integration format produced by
+/// `schema_to_json` back into an Arrow `Schema`.
+pub fn schema_from_json(schema_json: &serde_json::Value) -> Result<arrow_schema::Schema, String> {
+    let fields_array = schema_json
+        .get("fields")
+        .and_then(|f| f.as_array())
+        .ok_or("Schema JSON must have 'fields' array")?;
+
+    let fields: Result<Vec<Field>, String> = fields_array
+        .iter()
+        .map(integration_field_from_json)
+        .collect();
+    Ok(arrow_schema::Schema::new(fields?))
+}
+
+/// Render a single `Field` as `{"name", "nullable", "type", "children"}` (and
+/// `"metadata"` as an array of `{"key","value"}` pairs when non-empty), the
+/// shape the Arrow JSON integration format uses for every field, including
+/// nested struct/list/map children.
+fn integration_field_to_json(field: &Field) -> serde_json::Value {
+    let mut json = serde_json::json!({
+        "name": field.name(),
+        "nullable": field.is_nullable(),
+        "type": integration_data_type_to_json(field.data_type()),
+    });
+    if let Some(children) = integration_children(field.data_type()) {
+        json["children"] = serde_json::Value::Array(children);
+    }
+    if !field.metadata().is_empty() {
+        let entries: Vec<serde_json::Value> = field
+            .metadata()
+            .iter()
+            .map(|(k, v)| serde_json::json!({ "key": k, "value": v }))
+            .collect();
+        json["metadata"] = serde_json::Value::Array(entries);
+    }
+    json
+}
+
+/// Parse a single integration-format field descriptor back into a `Field`.
+fn integration_field_from_json(field_json: &serde_json::Value) -> Result<Field, String> {
+    let name = field_json
+        .get("name")
+        .and_then(|n| n.as_str())
+        .ok_or("Field must have 'name' string")?
+        .to_string();
+    let nullable = field_json
+        .get("nullable")
+        .and_then(|n| n.as_bool())
+        .unwrap_or(true);
+    let type_obj = field_json.get("type").ok_or("Field must have 'type'")?;
+    let children = field_json
+        .get("children")
+        .and_then(|c| c.as_array())
+        .cloned()
+        .unwrap_or_default();
+
+    let data_type = integration_json_to_data_type(type_obj, &children)?;
+    let metadata: std::collections::HashMap<String, String> = field_json
+        .get("metadata")
+        .and_then(|m| m.as_array())
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(|e| {
+                    let key = e.get("key")?.as_str()?;
+                    let value = e.get("value")?.as_str()?;
+                    Some((key.to_string(), value.to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    Ok(Field::new(name, data_type, nullable).with_metadata(metadata))
+}
+
+/// The `"children"` fields a `DataType` needs alongside its `"type"` object,
+/// or `None` for types with no children.
+fn integration_children(data_type: &DataType) -> Option<Vec<serde_json::Value>> {
+    match data_type {
+        DataType::List(item) | DataType::LargeList(item) | DataType::FixedSizeList(item, _) => {
+            Some(vec![integration_field_to_json(item)])
+        }
+        DataType::Struct(fields) => Some(
+            fields
+                .iter()
+                .map(|f| integration_field_to_json(f))
+                .collect(),
+        ),
+        DataType::Map(entries, _) => Some(vec![integration_field_to_json(entries)]),
+        _ => None,
+    }
+}
+
+/// Render a `DataType` as the canonical Arrow JSON integration-format type
+/// object, the inverse of `integration_json_to_data_type`.
+fn integration_data_type_to_json(data_type: &DataType) -> serde_json::Value {
+    match data_type {
+        DataType::Boolean => serde_json::json!({ "name": "bool" }),
+        DataType::Int8 => serde_json::json!({ "name": "int", "bitWidth": 8, "isSigned": true }),
+        DataType::Int16 => serde_json::json!({ "name": "int", "bitWidth": 16, "isSigned": true }),
+        DataType::Int32 => serde_json::json!({ "name": "int", "bitWidth": 32, "isSigned": true }),
+        DataType::Int64 => serde_json::json!({ "name": "int", "bitWidth": 64, "isSigned": true }),
+        DataType::UInt8 => serde_json::json!({ "name": "int", "bitWidth": 8, "isSigned": false }),
+        DataType::UInt16 => serde_json::json!({ "name": "int", "bitWidth": 16, "isSigned": false }),
+        DataType::UInt32 => serde_json::json!({ "name": "int", "bitWidth": 32, "isSigned": false }),
+        DataType::UInt64 => serde_json::json!({ "name": "int", "bitWidth": 64, "isSigned": false }),
+        DataType::Float16 => serde_json::json!({ "name": "floatingpoint", "precision": "HALF" }),
+        DataType::Float32 => serde_json::json!({ "name": "floatingpoint", "precision": "SINGLE" }),
+        DataType::Float64 => serde_json::json!({ "name": "floatingpoint", "precision": "DOUBLE" }),
+        DataType::Utf8 => serde_json::json!({ "name": "utf8" }),
+        DataType::LargeUtf8 => serde_json::json!({ "name": "largeutf8" }),
+        DataType::Binary => serde_json::json!({ "name": "binary" }),
+        DataType::LargeBinary => serde_json::json!({ "name": "largebinary" }),
+        DataType::FixedSizeBinary(byte_width) => {
+            serde_json::json!({ "name": "fixedsizebinary", "byteWidth": byte_width })
+        }
+        DataType::Date32 => serde_json::json!({ "name": "date", "unit": "DAY" }),
+        DataType::Date64 => serde_json::json!({ "name": "date", "unit": "MILLISECOND" }),
+        DataType::Time32(unit) => serde_json::json!({
+            "name": "time",
+            "unit": integration_time_unit_str(unit),
+            "bitWidth": 32,
+        }),
+        DataType::Time64(unit) => serde_json::json!({
+            "name": "time",
+            "unit": integration_time_unit_str(unit),
+            "bitWidth": 64,
+        }),
+        DataType::Timestamp(unit, tz) => {
+            let mut json = serde_json::json!({
+                "name": "timestamp",
+                "unit": integration_time_unit_str(unit),
+            });
+            if let Some(tz) = tz {
+                json["timezone"] = serde_json::json!(tz);
+            }
+            json
+        }
+        DataType::Decimal128(precision, scale) => serde_json::json!({
+            "name": "decimal",
+            "precision": precision,
+            "scale": scale,
+            "bitWidth": 128,
+        }),
+        DataType::Decimal256(precision, scale) => serde_json::json!({
+            "name": "decimal",
+            "precision": precision,
+            "scale": scale,
+            "bitWidth": 256,
+        }),
+        DataType::List(_) => serde_json::json!({ "name": "list" }),
+        DataType::LargeList(_) => serde_json::json!({ "name": "largelist" }),
+        DataType::FixedSizeList(_, size) => {
+            serde_json::json!({ "name": "fixedsizelist", "listSize": size })
+        }
+        DataType::Struct(_) => serde_json::json!({ "name": "struct" }),
+        DataType::Map(_, keys_sorted) => {
+            serde_json::json!({ "name": "map", "keysSorted": keys_sorted })
+        }
+        other => serde_json::json!({ "name": "unsupported", "debug": format!("{:?}", other) }),
+    }
+}
+
+/// Parse a canonical Arrow JSON integration-format `"type"` object (plus its
+/// sibling `"children"` array, for nested types) back into a `DataType`.
+fn integration_json_to_data_type(
+    type_obj: &serde_json::Value,
+    children: &[serde_json::Value],
+) -> Result<DataType, String> {
+    let name = type_obj
+        .get("name")
+        .and_then(|n| n.as_str())
+        .ok_or("Type object must have a 'name' string")?;
+
+    match name {
+        "bool" => Ok(DataType::Boolean),
+        "int" => {
+            let bit_width = type_obj
+                .get("bitWidth")
+                .and_then(|b| b.as_u64())
+                .ok_or("int type requires 'bitWidth'")?;
+            let is_signed = type_obj
+                .get("isSigned")
+                .and_then(|s| s.as_bool())
+                .ok_or("int type requires 'isSigned'")?;
+            match (bit_width, is_signed) {
+                (8, true) => Ok(DataType::Int8),
+                (16, true) => Ok(DataType::Int16),
+                (32, true) => Ok(DataType::Int32),
+                (64, true) => Ok(DataType::Int64),
+                (8, false) => Ok(DataType::UInt8),
+                (16, false) => Ok(DataType::UInt16),
+                (32, false) => Ok(DataType::UInt32),
+                (64, false) => Ok(DataType::UInt64),
+                (bits, signed) => Err(format!(
+                    "Unsupported int bitWidth/isSigned combination: {}/{}",
+                    bits, signed
+                )),
+            }
+        }
+        "floatingpoint" => {
+            let precision = type_obj
+                .get("precision")
+                .and_then(|p| p.as_str())
+                .ok_or("floatingpoint type requires 'precision'")?;
+            match precision {
+                "HALF" => Ok(DataType::Float16),
+                "SINGLE" => Ok(DataType::Float32),
+                "DOUBLE" => Ok(DataType::Float64),
+                other => Err(format!("Unsupported floatingpoint precision: {}", other)),
+            }
+        }
+        "utf8" => Ok(DataType::Utf8),
+        "largeutf8" => Ok(DataType::LargeUtf8),
+        "binary" => Ok(DataType::Binary),
+        "largebinary" => Ok(DataType::LargeBinary),
+        "fixedsizebinary" => {
+            let byte_width = type_obj
+                .get("byteWidth")
+                .and_then(|b| b.as_i64())
+                .ok_or("fixedsizebinary type requires 'byteWidth'")?;
+            Ok(DataType::FixedSizeBinary(byte_width as i32))
+        }
+        "date" => {
+            let unit = type_obj
+                .get("unit")
+                .and_then(|u| u.as_str())
+                .ok_or("date type requires 'unit'")?;
+            match unit {
+                "DAY" => Ok(DataType::Date32),
+                "MILLISECOND" => Ok(DataType::Date64),
+                other => Err(format!("Unsupported date unit: {}", other)),
+            }
+        }
+        "time" => {
+            let unit = integration_parse_time_unit(type_obj)?;
+            let bit_width = type_obj.get("bitWidth").and_then(|b| b.as_u64());
+            match (bit_width, unit) {
+                (Some(32) | None, TimeUnit::Second) => Ok(DataType::Time32(TimeUnit::Second)),
+                (Some(32) | None, TimeUnit::Millisecond) => {
+                    Ok(DataType::Time32(TimeUnit::Millisecond))
+                }
+                (Some(64) | None, TimeUnit::Microsecond) => {
+                    Ok(DataType::Time64(TimeUnit::Microsecond))
                 }
+                (Some(64) | None, TimeUnit::Nanosecond) => {
+                    Ok(DataType::Time64(TimeUnit::Nanosecond))
+                }
+                _ => Err("Unsupported time unit/bitWidth combination".to_string()),
+            }
+        }
+        "timestamp" => {
+            let unit = integration_parse_time_unit(type_obj)?;
+            let tz = type_obj
+                .get("timezone")
+                .and_then(|t| t.as_str())
+                .map(Arc::from);
+            Ok(DataType::Timestamp(unit, tz))
+        }
+        "decimal" => {
+            let precision = type_obj
+                .get("precision")
+                .and_then(|p| p.as_u64())
+                .ok_or("decimal type requires 'precision'")?;
+            let scale = type_obj
+                .get("scale")
+                .and_then(|s| s.as_i64())
+                .ok_or("decimal type requires 'scale'")?;
+            let bit_width = type_obj
+                .get("bitWidth")
+                .and_then(|b| b.as_u64())
+                .unwrap_or(128);
+            if bit_width == 256 {
+                Ok(DataType::Decimal256(precision as u8, scale as i8))
+            } else {
+                Ok(DataType::Decimal128(precision as u8, scale as i8))
+            }
+        }
+        "list" => {
+            let item = integration_single_child(children)?;
+            Ok(DataType::List(Arc::new(item)))
+        }
+        "largelist" => {
+            let item = integration_single_child(children)?;
+            Ok(DataType::LargeList(Arc::new(item)))
+        }
+        "fixedsizelist" => {
+            let size = type_obj
+                .get("listSize")
+                .and_then(|s| s.as_i64())
+                .ok_or("fixedsizelist type requires 'listSize'")?;
+            let item = integration_single_child(children)?;
+            Ok(DataType::FixedSizeList(Arc::new(item), size as i32))
+        }
+        "struct" => {
+            let fields: Result<Vec<Field>, String> =
+                children.iter().map(integration_field_from_json).collect();
+            Ok(DataType::Struct(Fields::from(fields?)))
+        }
+        "map" => {
+            let keys_sorted = type_obj
+                .get("keysSorted")
+                .and_then(|k| k.as_bool())
+                .unwrap_or(false);
+            let entries = integration_single_child(children)?;
+            Ok(DataType::Map(Arc::new(entries), keys_sorted))
+        }
+        other => Err(format!("Unsupported type name: {}", other)),
+    }
+}
+
+fn integration_single_child(children: &[serde_json::Value]) -> Result<Field, String> {
+    let child = children
+        .first()
+        .ok_or("Type requires exactly one 'children' entry")?;
+    integration_field_from_json(child)
+}
+
+fn integration_time_unit_str(unit: &TimeUnit) -> &'static str {
+    match unit {
+        TimeUnit::Second => "SECOND",
+        TimeUnit::Millisecond => "MILLISECOND",
+        TimeUnit::Microsecond => "MICROSECOND",
+        TimeUnit::Nanosecond => "NANOSECOND",
+    }
+}
+
+fn integration_parse_time_unit(type_obj: &serde_json::Value) -> Result<TimeUnit, String> {
+    let unit = type_obj
+        .get("unit")
+        .and_then(|u| u.as_str())
+        .ok_or("type requires 'unit'")?;
+    match unit {
+        "SECOND" => Ok(TimeUnit::Second),
+        "MILLISECOND" => Ok(TimeUnit::Millisecond),
+        "MICROSECOND" => Ok(TimeUnit::Microsecond),
+        "NANOSECOND" => Ok(TimeUnit::Nanosecond),
+        other => Err(format!("Unsupported time unit: {}", other)),
+    }
+}
+
+/// Parse a `fields` JSON array (used both for the top-level schema and for
+/// nested `struct` fields) into Arrow `Field`s.
+fn parse_fields_array(
+    fields_json: &[serde_json::Value],
+) -> Result<Vec<Field>, Box<dyn std::error::Error>> {
+    fields_json.iter().map(parse_field).collect()
+}
+
+/// Parse a single `{"name", "type", "nullable", ...}` field descriptor.
+/// `type` is a primitive/generic type string (`"int32"`,
+/// `"list<int32>"`, ...), a nested type object (see `parse_data_type_value`),
+/// or, for a struct, the bare string `"struct"` with a sibling `fields` array
+/// giving the nested field descriptors (the same shape as the top-level
+/// schema's `fields`). An optional `metadata` object of string values becomes
+/// the field's metadata.
+fn parse_field(field_json: &serde_json::Value) -> Result<Field, Box<dyn std::error::Error>> {
+    let name = field_json
+        .get("name")
+        .and_then(|n| n.as_str())
+        .ok_or("Field must have 'name' string")?
+        .to_string();
+
+    let nullable = field_json
+        .get("nullable")
+        .and_then(|n| n.as_bool())
+        .unwrap_or(true);
+
+    let type_value = field_json.get("type").ok_or("Field must have 'type'")?;
+
+    let data_type = if type_value.as_str() == Some("struct") {
+        let fields_json = field_json
+            .get("fields")
+            .and_then(|f| f.as_array())
+            .ok_or("Field of type 'struct' must have a 'fields' array")?;
+        DataType::Struct(Fields::from(parse_fields_array(fields_json)?))
+    } else {
+        parse_data_type_value(type_value)?
+    };
+
+    let metadata: std::collections::HashMap<String, String> = field_json
+        .get("metadata")
+        .and_then(|m| m.as_object())
+        .map(|m| {
+            m.iter()
+                .filter_map(|(k, v)| v.as_str().map(|s| (k.clone(), s.to_string())))
+                .collect()
+        })
+        .unwrap_or_default();
+    Ok(Field::new(name, data_type, nullable).with_metadata(metadata))
+}
+
+/// Parse a `type` value, which is either a type string (primitives and the
+/// generic `list<T>`/`large_list<T>`/`struct<...>`/`map<K,V>`/
+/// `fixed_size_list[T;N]`/`timestamp[unit,tz]`/`decimal128(p,s)` syntax) or a
+/// nested JSON object (`{"list": <type>}`, `{"large_list": <type>}`,
+/// `{"struct": [<field>, ...]}`, `{"map": {"key": <type>, "value": <type>}}`,
+/// `{"fixed_size_list": {"item": <type>, "size": N}}`) for callers that would
+/// rather build nested types as JSON trees than as strings.
+fn parse_data_type_value(
+    type_value: &serde_json::Value,
+) -> Result<DataType, Box<dyn std::error::Error>> {
+    match type_value {
+        serde_json::Value::String(s) => parse_data_type_str(s),
+        serde_json::Value::Object(obj) => {
+            if let Some(fields_json) = obj.get("struct").and_then(|v| v.as_array()) {
+                return Ok(DataType::Struct(Fields::from(parse_fields_array(
+                    fields_json,
+                )?)));
             }
-        };
+            if let Some(item_type) = obj.get("list") {
+                let item = parse_data_type_value(item_type)?;
+                return Ok(DataType::List(Arc::new(Field::new("item", item, true))));
+            }
+            if let Some(item_type) = obj.get("large_list") {
+                let item = parse_data_type_value(item_type)?;
+                return Ok(DataType::LargeList(Arc::new(Field::new(
+                    "item", item, true,
+                ))));
+            }
+            if let Some(fsl_obj) = obj.get("fixed_size_list") {
+                let item_type = fsl_obj
+                    .get("item")
+                    .ok_or("fixed_size_list type object requires an 'item'")?;
+                let size = fsl_obj
+                    .get("size")
+                    .and_then(|v| v.as_i64())
+                    .ok_or("fixed_size_list type object requires an integer 'size'")?;
+                let item = parse_data_type_value(item_type)?;
+                return Ok(DataType::FixedSizeList(
+                    Arc::new(Field::new("item", item, false)),
+                    size as i32,
+                ));
+            }
+            if let Some(map_obj) = obj.get("map") {
+                let key_type = map_obj
+                    .get("key")
+                    .ok_or("map type object requires a 'key'")?;
+                let value_type = map_obj
+                    .get("value")
+                    .ok_or("map type object requires a 'value'")?;
+                return Ok(build_map_type(
+                    parse_data_type_value(key_type)?,
+                    parse_data_type_value(value_type)?,
+                ));
+            }
+            if let Some(dict_obj) = obj.get("dictionary") {
+                let key_type = dict_obj
+                    .get("key")
+                    .ok_or("dictionary type object requires a 'key'")?;
+                let value_type = dict_obj
+                    .get("value")
+                    .ok_or("dictionary type object requires a 'value'")?;
+                return Ok(DataType::Dictionary(
+                    Box::new(parse_data_type_value(key_type)?),
+                    Box::new(parse_data_type_value(value_type)?),
+                ));
+            }
+            Err(format!("Unsupported nested type object: {}", type_value).into())
+        }
+        other => Err(format!("Field 'type' must be a string or object, got: {}", other).into()),
+    }
+}
+
+/// Parse a type string, recursing into the generic/nested forms the way
+/// arrow/delta kernel schema converters do, bottoming out at the flat
+/// primitive match.
+fn parse_data_type_str(type_str: &str) -> Result<DataType, Box<dyn std::error::Error>> {
+    let type_str = type_str.trim();
+
+    match type_str {
+        "int8" => return Ok(DataType::Int8),
+        "int16" => return Ok(DataType::Int16),
+        "int32" => return Ok(DataType::Int32),
+        "int64" => return Ok(DataType::Int64),
+        "float16" => return Ok(DataType::Float16),
+        "float32" => return Ok(DataType::Float32),
+        "float64" => return Ok(DataType::Float64),
+        "string" => return Ok(DataType::Utf8),
+        "large_string" => return Ok(DataType::LargeUtf8),
+        "binary" => return Ok(DataType::Binary),
+        "large_binary" => return Ok(DataType::LargeBinary),
+        "boolean" => return Ok(DataType::Boolean),
+        "date32" => return Ok(DataType::Date32),
+        "date64" => return Ok(DataType::Date64),
+        _ => {}
+    }
+
+    if let Some(inner) = strip_wrapper(type_str, "list<", ">") {
+        let item_type = parse_data_type_str(inner)?;
+        return Ok(DataType::List(Arc::new(Field::new(
+            "item", item_type, true,
+        ))));
+    }
+    if let Some(inner) = strip_wrapper(type_str, "large_list<", ">") {
+        let item_type = parse_data_type_str(inner)?;
+        return Ok(DataType::LargeList(Arc::new(Field::new(
+            "item", item_type, true,
+        ))));
+    }
+    if let Some(inner) = strip_wrapper(type_str, "struct<", ">") {
+        return Ok(DataType::Struct(Fields::from(parse_struct_field_list(
+            inner,
+        )?)));
+    }
+    if let Some(inner) = strip_wrapper(type_str, "map<", ">") {
+        let parts = split_top_level(inner, ',');
+        if parts.len() != 2 {
+            return Err(format!(
+                "map<K,V> expects exactly two type arguments, got: {}",
+                inner
+            )
+            .into());
+        }
+        let key_type = parse_data_type_str(&parts[0])?;
+        let value_type = parse_data_type_str(&parts[1])?;
+        return Ok(build_map_type(key_type, value_type));
+    }
+    if let Some(spec) = strip_wrapper(type_str, "timestamp[", "]") {
+        return parse_timestamp(spec);
+    }
+    if let Some(spec) = strip_wrapper(type_str, "decimal128(", ")") {
+        return parse_decimal128(spec);
+    }
+    if let Some(spec) = strip_wrapper(type_str, "decimal256(", ")") {
+        return parse_decimal256(spec);
+    }
+    if let Some(spec) = strip_wrapper(type_str, "time32[", "]") {
+        return parse_time32(spec);
+    }
+    if let Some(spec) = strip_wrapper(type_str, "time64[", "]") {
+        return parse_time64(spec);
+    }
+    if let Some(inner) = strip_wrapper(type_str, "dictionary<", ">") {
+        let parts = split_top_level(inner, ',');
+        if parts.len() != 2 {
+            return Err(format!(
+                "dictionary<K,V> expects exactly two type arguments, got: {}",
+                inner
+            )
+            .into());
+        }
+        let key_type = parse_data_type_str(&parts[0])?;
+        let value_type = parse_data_type_str(&parts[1])?;
+        return Ok(DataType::Dictionary(
+            Box::new(key_type),
+            Box::new(value_type),
+        ));
+    }
+    if let Some(inner) = strip_wrapper(type_str, "fixed_size_list[", "]") {
+        let parts = split_top_level(inner, ';');
+        if parts.len() != 2 {
+            return Err(format!(
+                "fixed_size_list[<type>;N] expects a type and a size, got: {}",
+                inner
+            )
+            .into());
+        }
+        let item_type = parse_data_type_str(&parts[0])?;
+        let size: i32 = parts[1]
+            .parse()
+            .map_err(|_| format!("Invalid vector dimension: {}", parts[1]))?;
+        return Ok(DataType::FixedSizeList(
+            Arc::new(Field::new("item", item_type, false)),
+            size,
+        ));
+    }
+
+    Err(format!("Unsupported data type: {}", type_str).into())
+}
+
+/// Parse the `name:type,name:type` field list inside a `struct<...>` string.
+fn parse_struct_field_list(inner: &str) -> Result<Vec<Field>, Box<dyn std::error::Error>> {
+    split_top_level(inner, ',')
+        .iter()
+        .map(|part| {
+            let (name, type_str) = part
+                .split_once(':')
+                .ok_or_else(|| format!("Expected 'name:type' in struct field, got: {}", part))?;
+            Ok(Field::new(
+                name.trim(),
+                parse_data_type_str(type_str.trim())?,
+                true,
+            ))
+        })
+        .collect()
+}
+
+/// Build the canonical `Map` representation: a non-nullable `entries` struct
+/// of a non-null `key` and a nullable `value`.
+fn build_map_type(key_type: DataType, value_type: DataType) -> DataType {
+    let entries = Field::new(
+        "entries",
+        DataType::Struct(Fields::from(vec![
+            Field::new("key", key_type, false),
+            Field::new("value", value_type, true),
+        ])),
+        false,
+    );
+    DataType::Map(Arc::new(entries), false)
+}
+
+/// Parse a `unit` or `unit,tz` timestamp spec, e.g. `"us"` or `"us,UTC"`.
+fn parse_timestamp(spec: &str) -> Result<DataType, Box<dyn std::error::Error>> {
+    let parts = split_top_level(spec, ',');
+    let unit_str = parts.first().ok_or("timestamp[] requires a unit")?;
+    let unit = match unit_str.as_str() {
+        "s" => TimeUnit::Second,
+        "ms" => TimeUnit::Millisecond,
+        "us" => TimeUnit::Microsecond,
+        "ns" => TimeUnit::Nanosecond,
+        other => return Err(format!("Unsupported timestamp unit: {}", other).into()),
+    };
+    let tz = parts.get(1).map(|s| Arc::from(s.as_str()));
+    Ok(DataType::Timestamp(unit, tz))
+}
+
+/// Parse a `precision,scale` decimal128 spec, e.g. `"38,10"`.
+fn parse_decimal128(spec: &str) -> Result<DataType, Box<dyn std::error::Error>> {
+    let parts = split_top_level(spec, ',');
+    if parts.len() != 2 {
+        return Err(format!(
+            "decimal128(precision,scale) expects two arguments, got: {}",
+            spec
+        )
+        .into());
+    }
+    let precision: u8 = parts[0]
+        .parse()
+        .map_err(|_| format!("Invalid decimal128 precision: {}", parts[0]))?;
+    let scale: i8 = parts[1]
+        .parse()
+        .map_err(|_| format!("Invalid decimal128 scale: {}", parts[1]))?;
+    Ok(DataType::Decimal128(precision, scale))
+}
 
-        fields.push(Field::new(name, data_type, nullable));
+/// Parse a `precision,scale` decimal256 spec, e.g. `"76,10"`.
+fn parse_decimal256(spec: &str) -> Result<DataType, Box<dyn std::error::Error>> {
+    let parts = split_top_level(spec, ',');
+    if parts.len() != 2 {
+        return Err(format!(
+            "decimal256(precision,scale) expects two arguments, got: {}",
+            spec
+        )
+        .into());
     }
+    let precision: u8 = parts[0]
+        .parse()
+        .map_err(|_| format!("Invalid decimal256 precision: {}", parts[0]))?;
+    let scale: i8 = parts[1]
+        .parse()
+        .map_err(|_| format!("Invalid decimal256 scale: {}", parts[1]))?;
+    Ok(DataType::Decimal256(precision, scale))
+}
+
+/// Parse a `time32[unit]` spec; only `s` and `ms` are valid for `Time32`.
+fn parse_time32(spec: &str) -> Result<DataType, Box<dyn std::error::Error>> {
+    let unit = match spec.trim() {
+        "s" => TimeUnit::Second,
+        "ms" => TimeUnit::Millisecond,
+        other => return Err(format!("Unsupported time32 unit: {}", other).into()),
+    };
+    Ok(DataType::Time32(unit))
+}
+
+/// Parse a `time64[unit]` spec; only `us` and `ns` are valid for `Time64`.
+fn parse_time64(spec: &str) -> Result<DataType, Box<dyn std::error::Error>> {
+    let unit = match spec.trim() {
+        "us" => TimeUnit::Microsecond,
+        "ns" => TimeUnit::Nanosecond,
+        other => return Err(format!("Unsupported time64 unit: {}", other).into()),
+    };
+    Ok(DataType::Time64(unit))
+}
 
-    Ok(Schema::new(fields))
+/// Strip a `prefix...suffix` wrapper (e.g. `"list<" ... ">"`) and return the
+/// inner text, or `None` if `s` isn't wrapped that way.
+fn strip_wrapper<'a>(s: &'a str, prefix: &str, suffix: &str) -> Option<&'a str> {
+    s.strip_prefix(prefix)?.strip_suffix(suffix)
+}
+
+/// Split `s` on top-level occurrences of `sep`, treating `<...>`, `[...]`,
+/// and `(...)` as nested and not splitting inside them. Used to parse
+/// generic type arguments (`map<K,V>`, `struct<a:T,b:U>`) where the
+/// arguments themselves may contain the same separator.
+fn split_top_level(s: &str, sep: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut current = String::new();
+
+    for c in s.chars() {
+        match c {
+            '<' | '[' | '(' => {
+                depth += 1;
+                current.push(c);
+            }
+            '>' | ']' | ')' => {
+                depth -= 1;
+                current.push(c);
+            }
+            c if c == sep && depth == 0 => {
+                parts.push(current.trim().to_string());
+                current = String::new();
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.trim().is_empty() {
+        parts.push(current.trim().to_string());
+    }
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema_round_trip(schema: &arrow_schema::Schema) {
+        let json = arrow_schema_to_json(schema);
+        let parsed = create_arrow_schema_from_json(&json).unwrap();
+        assert_eq!(&parsed, schema, "compact DSL round trip changed the schema");
+    }
+
+    fn integration_round_trip(schema: &arrow_schema::Schema) {
+        let json = schema_to_json(schema);
+        let parsed = schema_from_json(&json).unwrap();
+        assert_eq!(
+            &parsed, schema,
+            "integration-format round trip changed the schema"
+        );
+    }
+
+    #[test]
+    fn struct_field_round_trips() {
+        let schema = arrow_schema::Schema::new(vec![Field::new(
+            "point",
+            DataType::Struct(Fields::from(vec![
+                Field::new("x", DataType::Float64, false),
+                Field::new("y", DataType::Float64, false),
+            ])),
+            true,
+        )]);
+        schema_round_trip(&schema);
+        integration_round_trip(&schema);
+    }
+
+    #[test]
+    fn list_of_struct_round_trips() {
+        let item = DataType::Struct(Fields::from(vec![Field::new("tag", DataType::Utf8, true)]));
+        let schema = arrow_schema::Schema::new(vec![Field::new(
+            "tags",
+            DataType::List(Arc::new(Field::new("item", item, true))),
+            true,
+        )]);
+        schema_round_trip(&schema);
+        integration_round_trip(&schema);
+    }
+
+    #[test]
+    fn map_round_trips() {
+        let schema = arrow_schema::Schema::new(vec![Field::new(
+            "attributes",
+            build_map_type(DataType::Utf8, DataType::Int64),
+            true,
+        )]);
+        schema_round_trip(&schema);
+        integration_round_trip(&schema);
+    }
+
+    #[test]
+    fn dictionary_round_trips() {
+        let schema = arrow_schema::Schema::new(vec![Field::new(
+            "category",
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+            true,
+        )]);
+        schema_round_trip(&schema);
+    }
+
+    #[test]
+    fn fixed_size_list_of_primitive_round_trips() {
+        let schema = arrow_schema::Schema::new(vec![Field::new(
+            "vector",
+            DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Float32, false)), 8),
+            true,
+        )]);
+        schema_round_trip(&schema);
+    }
+
+    // Regression test: FixedSizeList of Struct used to be flattened through
+    // `data_type_str`'s string fallback and had no matching object-form
+    // parser branch, so it could be emitted but never parsed back.
+    #[test]
+    fn fixed_size_list_of_struct_round_trips() {
+        let item = DataType::Struct(Fields::from(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let schema = arrow_schema::Schema::new(vec![Field::new(
+            "points",
+            DataType::FixedSizeList(Arc::new(Field::new("item", item, false)), 3),
+            true,
+        )]);
+        schema_round_trip(&schema);
+    }
+
+    #[test]
+    fn legacy_fixed_size_list_string_form_still_parses() {
+        let parsed = parse_data_type_str("fixed_size_list[float32;4]").unwrap();
+        assert_eq!(
+            parsed,
+            DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Float32, false)), 4)
+        );
+    }
 }
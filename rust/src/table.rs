@@ -50,7 +50,7 @@ pub extern "C" fn simple_lancedb_create_table(
                         conn.create_table(&name, empty_batches).execute().await
                     }) {
                         Ok(_) => SimpleResult::ok(),
-                        Err(e) => SimpleResult::error(format!("Failed to create table: {}", e)),
+                        Err(e) => SimpleResult::from_lancedb_error("Failed to create table", e),
                     }
                 }
                 Err(e) => SimpleResult::error(format!("Failed to create Arrow schema: {}", e)),
@@ -110,7 +110,7 @@ pub extern "C" fn simple_lancedb_create_table_with_ipc(
             conn.create_table(&name, empty_batches).execute().await
         }) {
             Ok(_) => SimpleResult::ok(),
-            Err(e) => SimpleResult::error(format!("Failed to create table: {}", e)),
+            Err(e) => SimpleResult::from_lancedb_error("Failed to create table", e),
         }
     });
 
@@ -143,7 +143,7 @@ pub extern "C" fn simple_lancedb_drop_table(
 
         match rt.block_on(async { conn.drop_table(&name, &[]).await }) {
             Ok(_) => SimpleResult::ok(),
-            Err(e) => SimpleResult::error(format!("Failed to drop table: {}", e)),
+            Err(e) => SimpleResult::from_lancedb_error("Failed to drop table", e),
         }
     });
 
@@ -184,7 +184,7 @@ pub extern "C" fn simple_lancedb_open_table(
                 }
                 SimpleResult::ok()
             }
-            Err(e) => SimpleResult::error(format!("Failed to open table: {}", e)),
+            Err(e) => SimpleResult::from_lancedb_error("Failed to open table", e),
         }
     });
 
@@ -270,7 +270,7 @@ pub extern "C" fn simple_lancedb_table_optimize(
                     }
                 }
             }
-            Err(e) => SimpleResult::error(format!("Failed to optimize table: {}", e)),
+            Err(e) => SimpleResult::from_lancedb_error("Failed to optimize table", e),
         }
     });
 